@@ -1,4 +1,21 @@
-use telebot_shared::data::{PostingRule, PostingRuleTrait};
+use chrono::{DateTime, Utc};
+use telebot_shared::{
+    cron::{CronExpression, CronLanguage},
+    data::{
+        MediaGroupPostingRuleItemType, PollPostingRuleResultsSummaryTarget, PostingRule,
+        PostingRuleBlackoutBehavior, PostingRuleMedia, PostingRuleTrait, QueueItem,
+        QueuePostingRuleEmptyQueueBehavior, TemplateVariable, TextPostingRuleContent,
+        TextPostingRuleVariantSelection,
+    },
+    date,
+};
+use teloxide::utils::html;
+
+const NEXT_FIRE_TIMES_COUNT: usize = 3;
+
+const QUEUE_PREVIEW_COUNT: usize = 10;
+
+const QUEUE_PREVIEW_LENGTH: usize = 200;
 
 pub fn format_rule(posting_rule: &PostingRule, chat_name: &str) -> String {
     let name = &posting_rule.name();
@@ -9,7 +26,9 @@ pub fn format_rule(posting_rule: &PostingRule, chat_name: &str) -> String {
         chat_name.to_string()
     };
 
-    let status = if posting_rule.is_active() {
+    let status = if posting_rule.is_completed() {
+        "🏁 ВЫПОЛНЕНО"
+    } else if posting_rule.is_active() {
         "🟢 ВКЛЮЧЕНО"
     } else {
         "🔴 ВЫКЛЮЧЕНО"
@@ -22,7 +41,7 @@ pub fn format_rule(posting_rule: &PostingRule, chat_name: &str) -> String {
     };
 
     let text = match posting_rule {
-        PostingRule::Text(text_posting_rule) => &text_posting_rule.content.text,
+        PostingRule::Text(text_posting_rule) => &format_text_content(&text_posting_rule.content),
         PostingRule::Poll(poll_posting_rule) => {
             let options = poll_posting_rule
                 .content
@@ -34,11 +53,106 @@ pub fn format_rule(posting_rule: &PostingRule, chat_name: &str) -> String {
 
             &format!("{}\n\n{}", poll_posting_rule.content.question, options)
         }
+        PostingRule::Photo(photo_posting_rule) => &format_media(
+            "🖼 Фото",
+            &photo_posting_rule.content.media,
+            photo_posting_rule.content.caption.as_deref(),
+        ),
+        PostingRule::Video(video_posting_rule) => &format_media(
+            "🎬 Видео",
+            &video_posting_rule.content.media,
+            video_posting_rule.content.caption.as_deref(),
+        ),
+        PostingRule::Document(document_posting_rule) => &format_media(
+            "📄 Документ",
+            &document_posting_rule.content.media,
+            document_posting_rule.content.caption.as_deref(),
+        ),
+        PostingRule::MediaGroup(media_group_posting_rule) => &media_group_posting_rule
+            .content
+            .items
+            .iter()
+            .map(|item| {
+                let kind = match item.media_type {
+                    MediaGroupPostingRuleItemType::Photo => "🖼 Фото",
+                    MediaGroupPostingRuleItemType::Video => "🎬 Видео",
+                    MediaGroupPostingRuleItemType::Document => "📄 Документ",
+                };
+
+                format_media(kind, &item.media, item.caption.as_deref())
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        PostingRule::Queue(queue_posting_rule) => {
+            let empty_queue_behavior = match queue_posting_rule.content.empty_queue_behavior {
+                QueuePostingRuleEmptyQueueBehavior::Deactivate => "выключить правило".to_string(),
+                QueuePostingRuleEmptyQueueBehavior::NotifyAdmins { chat_id } => {
+                    format!("уведомить чат <code>{}</code>", chat_id)
+                }
+            };
+
+            &format!(
+                "📥 Публикуется следующая запись из очереди\nКогда очередь пуста: <b>{}</b>",
+                empty_queue_behavior
+            )
+        }
+    };
+
+    let schedule = match posting_rule.run_at() {
+        Some(run_at) => format!("однократно {} ({})", run_at, posting_rule.timezone()),
+        None if !posting_rule.schedules().is_empty() => posting_rule
+            .schedules()
+            .iter()
+            .map(|entry| {
+                format_schedule(
+                    &entry.schedule,
+                    entry.timezone.as_deref().unwrap_or(posting_rule.timezone()),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+        None => format_schedule(posting_rule.schedule(), posting_rule.timezone()),
+    };
+
+    let next_fire_times = format_next_fire_times(posting_rule);
+    let dates = format_dates(posting_rule);
+    let blackout = format_blackout(posting_rule);
+
+    let close = match posting_rule {
+        PostingRule::Poll(poll_posting_rule) => match (
+            &poll_posting_rule.close_schedule,
+            poll_posting_rule.content.close_date,
+        ) {
+            (Some(close_schedule), _) => {
+                Some(format_schedule(close_schedule, posting_rule.timezone()))
+            }
+            (None, Some(close_date)) => Some(format_timestamp(close_date, posting_rule.timezone())),
+            (None, None) => None,
+        },
+        _ => None,
     };
 
-    let schedule = format_schedule(posting_rule.schedule(), posting_rule.timezone());
+    let close_schedule = match (posting_rule, close) {
+        (PostingRule::Poll(poll_posting_rule), Some(close)) => {
+            let results_summary = match &poll_posting_rule.results_summary {
+                Some(results_summary) => match results_summary.target {
+                    PollPostingRuleResultsSummaryTarget::SameChat => "в канал",
+                    PollPostingRuleResultsSummaryTarget::ActionLogChat => "в лог",
+                },
+                None => "нет",
+            };
 
-    let will_pin = if posting_rule.should_pin() {
+            format!(
+                "\nЗакрытие опроса: <b>{}</b>\nИтоги голосования: <b>{}</b>",
+                close, results_summary
+            )
+        }
+        _ => String::new(),
+    };
+
+    let will_pin = if posting_rule.should_pin() && posting_rule.unpin_previous() {
+        "✅ (с откреплением предыдущего)"
+    } else if posting_rule.should_pin() {
         "✅"
     } else {
         "❌"
@@ -46,34 +160,280 @@ pub fn format_rule(posting_rule: &PostingRule, chat_name: &str) -> String {
 
     // TODO: Add all posting rule information in the text
     let formatted_rule = format!(
-        "<b>{}</b>\n\nКанал: <b>{}</b>\nРасписание: <b>{}</b>\nЗакрепление: {}\nКорректность данных: {}\nСтатус: <b>{}</b>\n\n{}",
-        name, chat_name, schedule, will_pin, is_valid, status, text
+        "<b>{}</b>\n\nКанал: <b>{}</b>\nРасписание: <b>{}</b>{}{}{}{}\nЗакрепление: {}\nКорректность данных: {}\nСтатус: <b>{}</b>\n\n{}",
+        name, chat_name, schedule, next_fire_times, dates, close_schedule, blackout, will_pin, is_valid, status, text
     );
 
     formatted_rule
 }
 
+fn format_text_content(content: &TextPostingRuleContent) -> String {
+    if content.variants.is_empty() {
+        return content.text.clone();
+    }
+
+    let selection = match content.variant_selection {
+        TextPostingRuleVariantSelection::RoundRobin => "по очереди".to_string(),
+        TextPostingRuleVariantSelection::Random => "случайно".to_string(),
+        TextPostingRuleVariantSelection::RandomWithoutRepeat { last_posts } => {
+            format!("случайно, без повтора последних {}", last_posts)
+        }
+    };
+
+    let variants = content
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| format!("<b>Вариант {}</b>\n{}", i + 1, variant))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format!(
+        "🔀 Варианты текста ({}): <b>{}</b>\n\n{}",
+        content.variants.len(),
+        selection,
+        variants
+    )
+}
+
+fn format_media(kind: &str, media: &PostingRuleMedia, caption: Option<&str>) -> String {
+    let source = match media {
+        PostingRuleMedia::FileId { file_id } => format!("file_id <code>{}</code>", file_id),
+        PostingRuleMedia::Url { url } => url.to_string(),
+    };
+
+    match caption {
+        Some(caption) => format!("{}: {}\n\n{}", kind, source, caption),
+        None => format!("{}: {}", kind, source),
+    }
+}
+
+fn format_dates(posting_rule: &PostingRule) -> String {
+    let mut dates = String::new();
+
+    if let Some(start_date) = posting_rule.start_date() {
+        dates.push_str(&format!(
+            "\nНачало: <b>{}</b>",
+            format_timestamp(start_date, posting_rule.timezone())
+        ));
+    }
+
+    if let Some(end_date) = posting_rule.end_date() {
+        dates.push_str(&format!(
+            "\nОкончание: <b>{}</b>",
+            format_timestamp(end_date, posting_rule.timezone())
+        ));
+    }
+
+    dates
+}
+
+fn format_blackout(posting_rule: &PostingRule) -> String {
+    let mut blackout = String::new();
+
+    if let Some(posting_rule_blackout) = posting_rule.blackout() {
+        let behavior = match posting_rule_blackout.behavior {
+            PostingRuleBlackoutBehavior::Skip => "пропуск",
+            PostingRuleBlackoutBehavior::ShiftToNextAllowedDay => "перенос на следующий день",
+        };
+
+        blackout.push_str(&format!(
+            "\nКалендарь исключений: <b>{}</b> ({})",
+            posting_rule_blackout.calendar_id, behavior
+        ));
+    }
+
+    if let Some(last_skip) = posting_rule.last_skip() {
+        blackout.push_str(&format!(
+            "\nПоследний пропуск: <b>{}</b> — {}",
+            format_timestamp(last_skip.timestamp, posting_rule.timezone()),
+            last_skip.reason
+        ));
+    }
+
+    blackout
+}
+
+fn format_timestamp(timestamp: i64, timezone: &str) -> String {
+    let tz = match date::parse_timezone(timezone) {
+        Ok(tz) => tz,
+        Err(_) => return "INVALID_TIMEZONE".to_string(),
+    };
+
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.with_timezone(&tz).format("%d.%m.%Y %H:%M").to_string())
+        .unwrap_or_else(|| "INVALID_DATE".to_string())
+}
+
 fn format_schedule(schedule: &str, timezone: &str) -> String {
-    let parts: Vec<&str> = schedule.split_whitespace().collect();
+    match CronExpression::parse(schedule) {
+        Ok(expression) => format!(
+            "{} ({})",
+            expression.describe(CronLanguage::Russian),
+            timezone
+        ),
+        Err(_) => format!("{} ({}, некорректное выражение)", schedule.trim(), timezone),
+    }
+}
+
+fn format_next_fire_times(posting_rule: &PostingRule) -> String {
+    if !posting_rule.is_active() || posting_rule.is_completed() || posting_rule.is_one_shot() {
+        return String::new();
+    }
 
-    let minutes = parts[0];
-    let hours = parts[1];
-    let day_of_week = map_day_of_week(parts[4]);
+    let entries = if posting_rule.schedules().is_empty() {
+        vec![(posting_rule.schedule(), posting_rule.timezone())]
+    } else {
+        posting_rule
+            .schedules()
+            .iter()
+            .map(|entry| {
+                (
+                    entry.schedule.as_str(),
+                    entry.timezone.as_deref().unwrap_or(posting_rule.timezone()),
+                )
+            })
+            .collect()
+    };
 
-    format!("{} в {}:{} ({})", day_of_week, hours, minutes, timezone)
+    let now = Utc::now();
+
+    let after = posting_rule
+        .start_date()
+        .and_then(|start_date| DateTime::from_timestamp(start_date, 0))
+        .filter(|start_date| *start_date > now)
+        .unwrap_or(now);
+
+    let mut fire_times = entries
+        .iter()
+        .filter_map(|(schedule, timezone)| {
+            let expression = CronExpression::parse(schedule).ok()?;
+            let tz = date::parse_timezone(timezone).ok()?;
+
+            Some(expression.next_fire_times(after, tz, NEXT_FIRE_TIMES_COUNT))
+        })
+        .flatten()
+        .filter(|fire_time| {
+            posting_rule
+                .end_date()
+                .is_none_or(|end_date| fire_time.timestamp() <= end_date)
+        })
+        .collect::<Vec<_>>();
+
+    fire_times.sort_by_key(|fire_time| fire_time.timestamp());
+    fire_times.dedup_by_key(|fire_time| fire_time.timestamp());
+
+    if fire_times.is_empty() {
+        return String::new();
+    }
+
+    let fire_times = fire_times
+        .iter()
+        .take(NEXT_FIRE_TIMES_COUNT)
+        .map(|fire_time| fire_time.format("%d.%m.%Y %H:%M").to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("\nБлижайшие запуски: <b>{}</b>", fire_times)
 }
 
-fn map_day_of_week(day: &str) -> String {
-    match day {
-        "1" => "каждое воскресенье",
-        "2" => "каждый понедельник",
-        "3" => "каждый вторник",
-        "4" => "каждую среду",
-        "5" => "каждый четверг",
-        "6" => "каждую пятницу",
-        "7" => "каждую субботу",
-        "?" => "каждый день",
-        _ => "INVALID_DAY",
+pub fn format_template_variables(
+    variables: &[TemplateVariable],
+    chat_names: &[(i64, String)],
+) -> String {
+    let mut sections = vec![];
+
+    let bot_variables = variables
+        .iter()
+        .filter(|variable| variable.chat_id.is_none())
+        .collect::<Vec<_>>();
+
+    if !bot_variables.is_empty() {
+        sections.push(format_template_variables_section(
+            "Для всех чатов",
+            &bot_variables,
+        ));
     }
-    .to_string()
+
+    for (chat_id, chat_name) in chat_names {
+        let chat_variables = variables
+            .iter()
+            .filter(|variable| variable.chat_id == Some(*chat_id))
+            .collect::<Vec<_>>();
+
+        sections.push(format_template_variables_section(
+            &format!("{} (<code>{}</code>)", html::escape(chat_name), chat_id),
+            &chat_variables,
+        ));
+    }
+
+    if sections.is_empty() {
+        sections.push("Переменные не заданы".to_string());
+    }
+
+    format!(
+        "<b>🔤 Переменные</b>\n\n{}\n\n/setvar имя значение — задать переменную\n/setvar chat_id:имя значение — задать для отдельного чата\n/delvar имя — удалить переменную",
+        sections.join("\n\n")
+    )
+}
+
+fn format_template_variables_section(title: &str, variables: &[&TemplateVariable]) -> String {
+    let mut variables = variables.to_vec();
+    variables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let lines = variables
+        .iter()
+        .map(|variable| {
+            format!(
+                "<code>{{vars.{}}}</code> = {}",
+                variable.name,
+                html::escape(&variable.value)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("<b>{}</b>\n{}", title, lines.join("\n"))
+}
+
+// Only the beginning of the queue is shown, long items are cut to keep the message within the Telegram limit
+
+pub fn format_queue(posting_rule: &PostingRule, items: &[QueueItem]) -> String {
+    let preview = if items.is_empty() {
+        "Очередь пуста".to_string()
+    } else {
+        let mut lines = items
+            .iter()
+            .take(QUEUE_PREVIEW_COUNT)
+            .enumerate()
+            .map(|(i, item)| {
+                let text = if item.text.chars().count() > QUEUE_PREVIEW_LENGTH {
+                    format!(
+                        "{}…",
+                        item.text
+                            .chars()
+                            .take(QUEUE_PREVIEW_LENGTH)
+                            .collect::<String>()
+                    )
+                } else {
+                    item.text.clone()
+                };
+
+                format!("<b>{}.</b> {}", i + 1, html::escape(&text))
+            })
+            .collect::<Vec<_>>();
+
+        if items.len() > QUEUE_PREVIEW_COUNT {
+            lines.push(format!("… и ещё {}", items.len() - QUEUE_PREVIEW_COUNT));
+        }
+
+        lines.join("\n\n")
+    };
+
+    format!(
+        "<b>📥 Очередь: {}</b>\nЗаписей: <b>{}</b>\n\n{}\n\n<code>/enqueue {} текст</code> — добавить запись в конец очереди, несколько записей разделяются строкой ---",
+        posting_rule.name(),
+        items.len(),
+        preview,
+        posting_rule.id()
+    )
 }
@@ -1,12 +1,15 @@
 use crate::{
     formatter,
-    processor::{access_validator, menus},
+    processor::{access_validator, menus, template_variables},
     TelegramBotClient,
 };
 use anyhow::Error;
 use telebot_shared::{
     aws::DynamoDbClient,
-    data::{BotData, PostingRule, PostingRuleRepository, PostingRuleTrait},
+    data::{
+        BotData, PostingRule, PostingRuleRepository, PostingRuleTrait, QueueItemRepository,
+        TemplateVariableRepository,
+    },
 };
 use teloxide::{
     dispatching::dialogue::GetChatId,
@@ -52,6 +55,21 @@ pub async fn process(
 
             bot.answer_callback_query(callback_query.id.clone()).await?;
         }
+        "list_variables" => {
+            let template_variable_repository =
+                TemplateVariableRepository::new(db.client.clone()).await?;
+            let text = template_variables::render_list(bot, &template_variable_repository).await?;
+
+            bot.edit_message_text_with_markup(
+                chat_id.clone(),
+                message_id,
+                &text,
+                &menus::list_variables_menu(),
+            )
+            .await?;
+
+            bot.answer_callback_query(callback_query.id.clone()).await?;
+        }
         "rule_details" => {
             let posting_rule_id = params[0];
 
@@ -80,6 +98,32 @@ pub async fn process(
 
             bot.answer_callback_query(callback_query.id.clone()).await?;
         }
+        "rule_queue" => {
+            let posting_rule_id = params[0];
+
+            let posting_rule = match posting_rule_repository.get(posting_rule_id).await? {
+                Some(posting_rule) if posting_rule.bot_id() == bot.bot_id => posting_rule,
+                _ => {
+                    bot.send_text(chat_id.clone(), "Правило не найдено").await?;
+                    return Ok(());
+                }
+            };
+
+            let queue_item_repository = QueueItemRepository::new(db.client.clone()).await?;
+            let items = queue_item_repository
+                .get_by_posting_rule(posting_rule.id())
+                .await?;
+
+            bot.edit_message_text_with_markup(
+                chat_id.clone(),
+                message_id,
+                &formatter::format_queue(&posting_rule, &items),
+                &menus::rule_queue_menu(&posting_rule),
+            )
+            .await?;
+
+            bot.answer_callback_query(callback_query.id.clone()).await?;
+        }
         "activate_rule" => {
             let posting_rule_id = params[0];
             let posting_rule = posting_rule_repository.get(posting_rule_id).await?;
@@ -92,9 +136,12 @@ pub async fn process(
                 }
             };
 
-            posting_rule.set_active(true);
+            posting_rule_repository
+                .set_active(posting_rule.id(), true)
+                .await?;
 
-            posting_rule_repository.put_item(&posting_rule).await?;
+            posting_rule.set_active(true);
+            posting_rule.set_completed(None);
 
             let posting_rules_chat_id: Recipient = posting_rule.chat_id().into();
             let chat_name = bot.get_chat_title(posting_rules_chat_id).await?;
@@ -123,9 +170,11 @@ pub async fn process(
                 }
             };
 
-            posting_rule.set_active(false);
+            posting_rule_repository
+                .set_active(posting_rule.id(), false)
+                .await?;
 
-            posting_rule_repository.put_item(&posting_rule).await?;
+            posting_rule.set_active(false);
 
             let posting_rules_chat_id: Recipient = posting_rule.chat_id().into();
             let chat_name = bot.get_chat_title(posting_rules_chat_id).await?;
@@ -14,7 +14,7 @@ pub async fn process(
     let bot = TelegramBotClient::new(bot_data).await?;
 
     if let UpdateKind::Message(msg) = &update.kind {
-        message::process(msg, update, &bot, bot_data).await?;
+        message::process(msg, update, &bot, bot_data, db).await?;
         return Ok(());
     }
 
@@ -2,9 +2,22 @@ use telebot_shared::data::{PostingRule, PostingRuleTrait};
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
 pub fn main_menu() -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![InlineKeyboardButton::callback(
+            "📋 Список правил",
+            "list_rules",
+        )],
+        vec![InlineKeyboardButton::callback(
+            "🔤 Переменные",
+            "list_variables",
+        )],
+    ])
+}
+
+pub fn list_variables_menu() -> InlineKeyboardMarkup {
     InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
-        "📋 Список правил",
-        "list_rules",
+        "⬅️ Назад",
+        "back:main_menu",
     )]])
 }
 
@@ -46,11 +59,26 @@ pub fn rule_details_menu(posting_rule: &PostingRule) -> InlineKeyboardMarkup {
         )]
     };
 
-    InlineKeyboardMarkup::new(vec![
-        action,
-        vec![InlineKeyboardButton::callback(
-            "⬅️ Назад",
-            "back:list_rules",
-        )],
-    ])
+    let mut buttons = vec![action];
+
+    if let PostingRule::Queue(_) = posting_rule {
+        buttons.push(vec![InlineKeyboardButton::callback(
+            "📥 Очередь",
+            format!("rule_queue:{}", posting_rule.id()),
+        )]);
+    }
+
+    buttons.push(vec![InlineKeyboardButton::callback(
+        "⬅️ Назад",
+        "back:list_rules",
+    )]);
+
+    InlineKeyboardMarkup::new(buttons)
+}
+
+pub fn rule_queue_menu(posting_rule: &PostingRule) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
+        "⬅️ Назад",
+        format!("rule_details:{}", posting_rule.id()),
+    )]])
 }
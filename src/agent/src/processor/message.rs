@@ -1,9 +1,9 @@
 use crate::{
-    processor::{access_validator, menus},
+    processor::{access_validator, menus, queue, template_variables},
     TelegramBotClient,
 };
 use anyhow::Error;
-use telebot_shared::data::BotData;
+use telebot_shared::{aws::DynamoDbClient, data::BotData};
 use teloxide::{
     dispatching::dialogue::GetChatId,
     types::{Message, Recipient, Update},
@@ -14,6 +14,7 @@ pub async fn process(
     update: &Update,
     bot: &TelegramBotClient,
     bot_data: &BotData,
+    db: &DynamoDbClient,
 ) -> Result<(), Error> {
     let chat_id: Recipient = match update.chat_id().unwrap().as_user() {
         Some(user) => user.into(),
@@ -27,9 +28,25 @@ pub async fn process(
         false => return Ok(()),
     }
 
-    if let Some("/start") = message.text() {
-        bot.send_text_with_markup(chat_id.clone(), "🏠 Главное меню", &menus::main_menu())
-            .await?;
+    let text = match message.text() {
+        Some(text) => text,
+        None => return Ok(()),
+    };
+
+    let command = text.split_whitespace().next().unwrap_or_default();
+
+    match command {
+        "/start" => {
+            bot.send_text_with_markup(chat_id.clone(), "🏠 Главное меню", &menus::main_menu())
+                .await?;
+        }
+        "/vars" | "/setvar" | "/delvar" => {
+            template_variables::process_command(text, chat_id, bot, db).await?;
+        }
+        "/enqueue" => {
+            queue::process_command(text, chat_id, bot, db).await?;
+        }
+        _ => (),
     }
 
     Ok(())
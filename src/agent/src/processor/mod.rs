@@ -4,5 +4,7 @@ mod core;
 mod menus;
 mod message;
 mod poll_answer;
+mod queue;
+mod template_variables;
 
 pub use core::process;
@@ -1,15 +1,19 @@
 use crate::TelegramBotClient;
 use anyhow::{anyhow, Error};
-use chrono_tz::Tz;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use telebot_shared::{
     aws::DynamoDbClient,
     data::{
-        PollActionLog, PollActionLogOutput, PollActionLogRecord, PollActionLogRepository, PollPost,
-        Post, PostRepository, PostTrait,
+        PollActionLog, PollActionLogOutput, PollActionLogRecord, PollActionLogRecordWrite,
+        PollActionLogRepository, PollPost, Post, PostRepository, PostTrait,
     },
+    date,
 };
 use teloxide::types::{PollAnswer, Recipient, Update};
+use tracing::{info, warn};
+
+const MAX_SAVE_ATTEMPTS: u32 = 5;
+const SAVE_BACKOFF_BASE_MS: u64 = 50;
 
 pub async fn process(
     poll_answer: &PollAnswer,
@@ -31,6 +35,15 @@ pub async fn process(
         }
     };
 
+    if action_log.contains_update(update.id) {
+        info!(
+            update_id = update.id.0,
+            poll_id = %action_log.id,
+            "Poll answer update already recorded, skipping"
+        );
+        return Ok(());
+    }
+
     let post = post_repository
         .get_by_chat_and_message(action_log.chat_id, action_log.message_id)
         .await?;
@@ -59,33 +72,90 @@ pub async fn process(
 
     let poll_options = &poll_post.content.options;
 
-    let (option_id, option_text) = if !poll_answer.option_ids.is_empty() {
-        let option_id = poll_answer.option_ids[0];
-        let option_text = poll_options[option_id as usize].clone();
-        (Some(option_id as i32), Some(option_text))
-    } else {
-        (None, None)
-    };
+    let option_ids = poll_answer
+        .option_ids
+        .iter()
+        .map(|option_id| *option_id as i32)
+        .collect::<Vec<_>>();
 
-    // TODO: Check if the record with the same update_id elready exists and omit adding a new one in this case, to prevent duplicates when receiving the same update multiple times due to network issues or other reasons
+    let option_texts = poll_answer
+        .option_ids
+        .iter()
+        .map(|option_id| {
+            poll_options
+                .get(*option_id as usize)
+                .cloned()
+                .ok_or_else(|| anyhow!("Option {} not found in poll post", option_id))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
     let action_record = PollActionLogRecord::new(
         update.id,
         poll_answer.voter.user().unwrap(),
-        option_id,
-        option_text,
+        option_ids,
+        option_texts,
     );
 
-    let mut updated_action_log = action_log.clone();
-    updated_action_log.records.push(action_record);
+    if !save_action_record(&action_log, &action_record, &poll_action_log_repository).await? {
+        info!(
+            update_id = update.id.0,
+            poll_id = %action_log.id,
+            "Poll answer update already recorded, skipping"
+        );
+        return Ok(());
+    }
 
-    poll_action_log_repository.put(&updated_action_log).await?;
+    let updated_action_log = poll_action_log_repository
+        .get_by_poll_id(&action_log.id)
+        .await?
+        .ok_or_else(|| anyhow!("Poll action log {} not found after saving", action_log.id))?;
 
     update_action_log_message(&updated_action_log, &poll_post, bot).await?;
 
     Ok(())
 }
 
+// Answers to the same poll given at the same moment conflict on the log item, the write is retried
+// with a backoff so that no answer is lost. Returns false if the update has already been recorded
+
+async fn save_action_record(
+    action_log: &PollActionLog,
+    action_record: &PollActionLogRecord,
+    poll_action_log_repository: &PollActionLogRepository,
+) -> Result<bool, Error> {
+    for attempt in 1..=MAX_SAVE_ATTEMPTS {
+        match poll_action_log_repository
+            .put_record(action_log, action_record)
+            .await?
+        {
+            PollActionLogRecordWrite::Stored => return Ok(true),
+            PollActionLogRecordWrite::AlreadyExists => return Ok(false),
+            PollActionLogRecordWrite::Conflict => {}
+        }
+
+        if attempt == MAX_SAVE_ATTEMPTS {
+            break;
+        }
+
+        let backoff = Duration::from_millis(SAVE_BACKOFF_BASE_MS * 2u64.pow(attempt - 1));
+
+        warn!(
+            poll_id = %action_log.id,
+            attempt,
+            backoff_ms = backoff.as_millis() as u64,
+            "Poll action log write conflict, retrying"
+        );
+
+        tokio::time::sleep(backoff).await;
+    }
+
+    Err(anyhow!(
+        "Failed to save poll action log record for poll {} after {} attempts due to write conflicts",
+        action_log.id,
+        MAX_SAVE_ATTEMPTS
+    ))
+}
+
 async fn update_action_log_message(
     action_log: &PollActionLog,
     poll_post: &PollPost,
@@ -121,7 +191,7 @@ async fn update_action_log_message(
                         continue;
                     }
 
-                    if record.option_id == Some(target_option_id) {
+                    if record.has_option(target_option_id) {
                         target_option_timestamp = Some(record.timestamp);
                     } else if let Some(timestamp) = target_option_timestamp {
                         if record.timestamp > timestamp {
@@ -134,6 +204,8 @@ async fn update_action_log_message(
         }
     }
 
+    let tz = date::parse_timezone(&action_log.timezone)?;
+
     let mut actor_ids: Vec<_> = filtered_records.keys().copied().collect();
     actor_ids.sort();
 
@@ -141,38 +213,23 @@ async fn update_action_log_message(
         .iter()
         .map(|actor_id| {
             let records = &filtered_records[actor_id];
-            let last_name = records[0]
-                .actor_last_name
-                .clone()
-                .map_or("".to_string(), |ln| format!(" {}", ln));
-
-            let username = records[0]
-                .actor_username
-                .clone()
-                .map_or("".to_string(), |un| format!(" (@{})", un));
-
-            let actor_name = format!(
-                "<b>{}{}{}</b>",
-                records[0].actor_first_name, last_name, username
-            );
+            let actor_name = format!("<b>{}</b>", records[0].actor_name());
 
             let actions_list = records
                 .iter()
                 .map(|record| {
-                    let tz: Tz = action_log.timezone.parse().unwrap();
                     let date = chrono::DateTime::from_timestamp(record.timestamp, 0)
                         .unwrap()
                         .with_timezone(&tz)
                         .format("%d.%m.%Y %H:%M:%S");
 
-                    format!(
-                        "{} → {}",
-                        date,
-                        record
-                            .option_text
-                            .clone()
-                            .unwrap_or("<b>Голос отозван</b>".to_string())
-                    )
+                    let choice = if record.is_revoked() {
+                        "<b>Голос отозван</b>".to_string()
+                    } else {
+                        record.option_texts.join(", ")
+                    };
+
+                    format!("{} → {}", date, choice)
                 })
                 .collect::<Vec<String>>()
                 .join("\n");
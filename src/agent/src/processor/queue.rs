@@ -0,0 +1,163 @@
+use crate::TelegramBotClient;
+use anyhow::Error;
+use telebot_shared::{
+    aws::DynamoDbClient,
+    data::{
+        PostingRule, PostingRuleRepository, PostingRuleTrait, QueueItemRepository,
+        TemplateVariable, TemplateVariableRepository,
+    },
+    template::Template,
+};
+use teloxide::{types::Recipient, utils::html};
+
+const ENQUEUE_USAGE: &str = "Использование: <code>/enqueue id_правила текст</code>, несколько записей можно разделить строкой ---";
+
+const ITEM_SEPARATOR: &str = "---";
+
+// Items are appended to the end of the queue, the whole text after the rule id is kept with its line breaks
+
+pub async fn process_command(
+    text: &str,
+    chat_id: Recipient,
+    bot: &TelegramBotClient,
+    db: &DynamoDbClient,
+) -> Result<(), Error> {
+    let arguments = text
+        .trim()
+        .split_once(char::is_whitespace)
+        .map(|(_, arguments)| arguments.trim())
+        .unwrap_or_default();
+
+    let (posting_rule_id, items_text) = match arguments.split_once(char::is_whitespace) {
+        Some((posting_rule_id, items_text)) => (posting_rule_id, items_text),
+        None => {
+            bot.send_text(chat_id, ENQUEUE_USAGE).await?;
+            return Ok(());
+        }
+    };
+
+    let posting_rule_repository = PostingRuleRepository::new(db.client.clone()).await?;
+
+    let posting_rule = match posting_rule_repository.get(posting_rule_id).await? {
+        Some(posting_rule) if posting_rule.bot_id() == bot.bot_id => posting_rule,
+        _ => {
+            bot.send_text(chat_id, "Правило не найдено").await?;
+            return Ok(());
+        }
+    };
+
+    if !matches!(posting_rule, PostingRule::Queue(_)) {
+        bot.send_text(chat_id, "Правило не использует очередь")
+            .await?;
+        return Ok(());
+    }
+
+    let items = split_items(items_text);
+
+    if items.is_empty() {
+        bot.send_text(chat_id, ENQUEUE_USAGE).await?;
+        return Ok(());
+    }
+
+    if items.len() > QueueItemRepository::MAX_APPEND_ITEMS {
+        bot.send_text(
+            chat_id,
+            &format!(
+                "❌ За один раз можно добавить не больше {} записей",
+                QueueItemRepository::MAX_APPEND_ITEMS
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    // Items are posted as templates, so they are checked the same way as the texts of posting rules,
+    // including the custom variables defined for the rule's chat
+
+    let template_variable_repository = TemplateVariableRepository::new(db.client.clone()).await?;
+
+    let template_variables = TemplateVariable::values_for_chat(
+        &template_variable_repository
+            .get_by_bot(posting_rule.bot_id())
+            .await?,
+        posting_rule.chat_id().0,
+    );
+
+    let mut issues = vec![];
+
+    for (i, item) in items.iter().enumerate() {
+        match Template::parse(item) {
+            Ok(template) => issues.extend(
+                template
+                    .custom_variables()
+                    .into_iter()
+                    .filter(|name| !template_variables.contains_key(*name))
+                    .map(|name| {
+                        format!(
+                            "Запись {}: неизвестная переменная {{vars.{}}}",
+                            i + 1,
+                            html::escape(name)
+                        )
+                    }),
+            ),
+            Err(errors) => issues.extend(
+                errors
+                    .into_iter()
+                    .map(|error| format!("Запись {}: {}", i + 1, html::escape(&error.message))),
+            ),
+        }
+    }
+
+    if !issues.is_empty() {
+        bot.send_text(
+            chat_id,
+            &format!(
+                "❌ Записи не добавлены, исправьте ошибки в шаблонах:\n{}",
+                issues.join("\n")
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let queue_item_repository = QueueItemRepository::new(db.client.clone()).await?;
+
+    queue_item_repository
+        .append(posting_rule.id(), &items)
+        .await?;
+
+    bot.send_text(
+        chat_id,
+        &format!(
+            "✅ Добавлено записей в очередь <b>{}</b>: {}",
+            posting_rule.name(),
+            items.len()
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn split_items(text: &str) -> Vec<&str> {
+    let mut items = vec![];
+    let mut start = 0;
+    let mut position = 0;
+
+    for line in text.split_inclusive('\n') {
+        if line.trim() == ITEM_SEPARATOR {
+            items.push(&text[start..position]);
+            start = position + line.len();
+        }
+
+        position += line.len();
+    }
+
+    items.push(&text[start..]);
+
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
@@ -0,0 +1,151 @@
+use crate::{formatter, TelegramBotClient};
+use anyhow::Error;
+use telebot_shared::{
+    aws::DynamoDbClient,
+    data::{TemplateVariable, TemplateVariableRepository},
+};
+use teloxide::types::{ChatId, Recipient};
+
+const SET_USAGE: &str = "Использование: <code>/setvar имя значение</code> или <code>/setvar chat_id:имя значение</code> для отдельного чата";
+
+const DELETE_USAGE: &str =
+    "Использование: <code>/delvar имя</code> или <code>/delvar chat_id:имя</code>";
+
+const INVALID_NAME: &str =
+    "Имя переменной может содержать только строчные латинские буквы, цифры и символ _";
+
+// Variables are edited with text commands, the target is either a name or chat_id:name
+// for a value that overrides the bot-level one in a single chat
+
+pub async fn process_command(
+    text: &str,
+    chat_id: Recipient,
+    bot: &TelegramBotClient,
+    db: &DynamoDbClient,
+) -> Result<(), Error> {
+    let (command, arguments) = match text.trim().split_once(char::is_whitespace) {
+        Some((command, arguments)) => (command, arguments.trim()),
+        None => (text.trim(), ""),
+    };
+
+    match command {
+        "/vars" => {
+            let repository = TemplateVariableRepository::new(db.client.clone()).await?;
+            let text = render_list(bot, &repository).await?;
+
+            bot.send_text(chat_id, &text).await?;
+        }
+        "/setvar" => {
+            let (target, value) = match arguments.split_once(char::is_whitespace) {
+                Some((target, value)) if !value.trim().is_empty() => (target, value.trim()),
+                _ => {
+                    bot.send_text(chat_id, SET_USAGE).await?;
+                    return Ok(());
+                }
+            };
+
+            let (variable_chat_id, name) = match parse_target(target) {
+                Some(target) => target,
+                None => {
+                    bot.send_text(chat_id, INVALID_NAME).await?;
+                    return Ok(());
+                }
+            };
+
+            let repository = TemplateVariableRepository::new(db.client.clone()).await?;
+            let variable = TemplateVariable::new(&bot.bot_id, variable_chat_id, name, value);
+
+            repository.put(&variable).await?;
+
+            bot.send_text(
+                chat_id,
+                &format!(
+                    "✅ Переменная <code>{{vars.{}}}</code> сохранена{}",
+                    name,
+                    format_scope(variable_chat_id)
+                ),
+            )
+            .await?;
+        }
+        "/delvar" => {
+            if arguments.is_empty() {
+                bot.send_text(chat_id, DELETE_USAGE).await?;
+                return Ok(());
+            }
+
+            let (variable_chat_id, name) = match parse_target(arguments) {
+                Some(target) => target,
+                None => {
+                    bot.send_text(chat_id, INVALID_NAME).await?;
+                    return Ok(());
+                }
+            };
+
+            let repository = TemplateVariableRepository::new(db.client.clone()).await?;
+            let id = TemplateVariable::build_id(variable_chat_id, name);
+
+            let text = if repository.delete(&bot.bot_id, &id).await? {
+                format!(
+                    "🗑 Переменная <code>{{vars.{}}}</code> удалена{}",
+                    name,
+                    format_scope(variable_chat_id)
+                )
+            } else {
+                "Переменная не найдена".to_string()
+            };
+
+            bot.send_text(chat_id, &text).await?;
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+pub async fn render_list(
+    bot: &TelegramBotClient,
+    repository: &TemplateVariableRepository,
+) -> Result<String, Error> {
+    let variables = repository.get_by_bot(&bot.bot_id).await?;
+
+    let mut chat_ids = variables
+        .iter()
+        .filter_map(|variable| variable.chat_id)
+        .collect::<Vec<_>>();
+
+    chat_ids.sort();
+    chat_ids.dedup();
+
+    let mut chat_names = vec![];
+
+    for chat_id in chat_ids {
+        let recipient: Recipient = ChatId(chat_id).into();
+        let chat_name = bot
+            .get_chat_title(recipient)
+            .await
+            .unwrap_or_else(|_| chat_id.to_string());
+
+        chat_names.push((chat_id, chat_name));
+    }
+
+    Ok(formatter::format_template_variables(
+        &variables,
+        &chat_names,
+    ))
+}
+
+fn parse_target(target: &str) -> Option<(Option<i64>, &str)> {
+    let (chat_id, name) = match target.rsplit_once(':') {
+        Some((chat_id, name)) => (Some(chat_id.parse::<i64>().ok()?), name),
+        None => (None, target),
+    };
+
+    TemplateVariable::is_valid_name(name).then_some((chat_id, name))
+}
+
+fn format_scope(chat_id: Option<i64>) -> String {
+    match chat_id {
+        Some(chat_id) => format!(" для чата <code>{}</code>", chat_id),
+        None => String::new(),
+    }
+}
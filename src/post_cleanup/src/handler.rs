@@ -0,0 +1,117 @@
+use crate::{StreamAction, TelegramBotClient};
+use aws_lambda_events::event::dynamodb::{Event, EventRecord};
+use lambda_runtime::{Error, LambdaEvent};
+use serde_dynamo;
+use telebot_shared::{
+    aws::DynamoDbClient,
+    data::{BotDataRepository, PollActionLogRepository, Post, PostTrait},
+};
+use teloxide::types::{MessageId, Recipient};
+use tracing::info;
+
+const TTL_PRINCIPAL_ID: &str = "dynamodb.amazonaws.com";
+
+pub async fn handle(event: LambdaEvent<Event>) -> Result<(), Error> {
+    let (payload, _context) = event.into_parts();
+
+    if let Some(record) = payload.records.first() {
+        let action = StreamAction::from_event_name(&record.event_name);
+
+        info!(?record, "Received DynamoDB record");
+
+        match action {
+            StreamAction::Remove => {
+                if !is_expired_by_ttl(record) {
+                    info!("Post was not removed by TTL, skipping");
+                    return Ok(());
+                }
+
+                let post: Post = serde_dynamo::from_item(record.change.old_image.clone())?;
+
+                process_expired(&post).await?;
+            }
+            StreamAction::Insert | StreamAction::Modify => {}
+            StreamAction::Unknown => {
+                return Err(format!("Unknown event type: {}", record.event_name).into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Items deleted by TTL are marked with the DynamoDB service as the user identity,
+// everything else is a regular delete and must not touch the chat
+
+fn is_expired_by_ttl(record: &EventRecord) -> bool {
+    match &record.user_identity {
+        Some(user_identity) => {
+            user_identity.type_ == "Service" && user_identity.principal_id == TTL_PRINCIPAL_ID
+        }
+        None => false,
+    }
+}
+
+async fn process_expired(post: &Post) -> Result<(), Error> {
+    if !post.delete_on_expire() {
+        info!(
+            posting_rule_id = %post.posting_rule_id(),
+            "Delete on expire is disabled, skipping"
+        );
+        return Ok(());
+    }
+
+    let db = DynamoDbClient::new().await;
+
+    let bot_data_repository = BotDataRepository::new(&db).await?;
+
+    let bot_data = match bot_data_repository.get(post.bot_id()).await? {
+        Some(data) => data,
+        None => {
+            info!(bot_id = %post.bot_id(), "Bot data not found, skipping");
+            return Ok(());
+        }
+    };
+
+    let bot = TelegramBotClient::new(&bot_data).await?;
+
+    let chat_id: Recipient = post.chat_id().into();
+
+    let message_ids = match post {
+        Post::MediaGroup(media_group_post) => media_group_post
+            .content
+            .message_ids
+            .iter()
+            .map(|message_id| MessageId(*message_id))
+            .collect(),
+        _ => vec![post.message_id()],
+    };
+
+    for message_id in message_ids {
+        bot.delete_message(chat_id.clone(), message_id).await;
+    }
+
+    if let Post::Poll(poll_post) = post {
+        let poll_action_log_repository = PollActionLogRepository::new(db.client.clone()).await?;
+
+        if let Some(action_log) = poll_action_log_repository
+            .get_by_chat_and_message(poll_post.base.chat_id, poll_post.base.message_id)
+            .await?
+        {
+            bot.delete_message(
+                action_log.action_log_chat_id().into(),
+                action_log.action_log_message_id(),
+            )
+            .await;
+        }
+    }
+
+    info!(
+        posting_rule_id = %post.posting_rule_id(),
+        chat_id = post.base().chat_id,
+        message_id = post.base().message_id,
+        "Expired post deleted from chat"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod handler;
+pub mod stream;
+pub mod telegram;
+
+pub use handler::handle;
+pub use stream::StreamAction;
+pub use telegram::TelegramBotClient;
@@ -0,0 +1,17 @@
+use lambda_runtime::{service_fn, Error};
+use post_cleanup_lambda::handle;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .with_target(false)
+        .without_time()
+        .init();
+
+    lambda_runtime::run(service_fn(handle)).await
+}
@@ -0,0 +1,34 @@
+use telebot_shared::data::BotData;
+use teloxide::{
+    prelude::*,
+    types::{MessageId, Recipient},
+};
+use tracing::warn;
+
+pub struct TelegramBotClient {
+    pub bot_id: String,
+    bot: Bot,
+}
+
+impl TelegramBotClient {
+    pub async fn new(bot_data: &BotData) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            bot_id: bot_data.id.clone(),
+            bot: Bot::new(bot_data.token.clone()),
+        })
+    }
+
+    // Telegram refuses to delete messages that are already gone or too old,
+    // there is nothing to retry in that case so the failure is only logged
+
+    pub async fn delete_message(&self, chat_id: Recipient, message_id: MessageId) {
+        if let Err(err) = self.bot.delete_message(chat_id.clone(), message_id).await {
+            warn!(
+                chat_id = ?chat_id,
+                message_id = message_id.0,
+                error = %err,
+                "Failed to delete message"
+            );
+        }
+    }
+}
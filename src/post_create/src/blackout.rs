@@ -0,0 +1,154 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use telebot_shared::{
+    aws::SchedulerClient,
+    cron::CronExpression,
+    data::{
+        BlackoutCalendarRepository, PostingRule, PostingRuleBlackoutBehavior,
+        PostingRuleRepository, PostingRuleSkip, PostingRuleTrait,
+    },
+    date,
+};
+use tracing::warn;
+
+// Returns true if the scheduled fire time falls into a blackout period and the post must not be sent now,
+// the skip is stored on the rule so that admins can see it in the rule details
+
+pub async fn apply_blackout(
+    posting_rule: &PostingRule,
+    posting_rule_repository: &PostingRuleRepository,
+    blackout_calendar_repository: &BlackoutCalendarRepository,
+    scheduled_at: DateTime<Utc>,
+    function_arn: &str,
+) -> Result<bool, anyhow::Error> {
+    let blackout = match posting_rule.blackout() {
+        Some(blackout) => blackout,
+        None => return Ok(false),
+    };
+
+    let calendar = match blackout_calendar_repository
+        .get(posting_rule.bot_id(), &blackout.calendar_id)
+        .await?
+    {
+        Some(calendar) => calendar,
+        None => {
+            warn!(
+                posting_rule_id = %posting_rule.id(),
+                calendar_id = %blackout.calendar_id,
+                "Blackout calendar not found, posting as usual"
+            );
+            return Ok(false);
+        }
+    };
+
+    let tz = date::parse_timezone(posting_rule.timezone())?;
+
+    // A delayed or retried invocation must be checked against the day it was scheduled for
+
+    let fire_time = scheduled_at.with_timezone(&tz).naive_local();
+
+    let period = match calendar.find_period(fire_time.date()) {
+        Some(period) => period,
+        None => return Ok(false),
+    };
+
+    let reason = match blackout.behavior {
+        PostingRuleBlackoutBehavior::Skip => {
+            format!("Пропущено: {} ({})", period.description(), calendar.name)
+        }
+        PostingRuleBlackoutBehavior::ShiftToNextAllowedDay => {
+            match calendar.next_allowed_date(fire_time.date()) {
+                // A daily rule already posts on the next allowed day, a shift would post twice
+                Some(next_date) if has_regular_fire(posting_rule, next_date) => format!(
+                    "Пропущено, {} уже есть публикация по расписанию: {} ({})",
+                    next_date.format("%d.%m.%Y"),
+                    period.description(),
+                    calendar.name
+                ),
+                Some(next_date) => {
+                    let time = fire_time.time().with_second(0).unwrap_or(fire_time.time());
+                    let run_at = NaiveDateTime::new(next_date, time)
+                        .format(date::RUN_AT_FORMAT)
+                        .to_string();
+
+                    let scheduler = SchedulerClient::new(function_arn).await?;
+                    scheduler
+                        .create_or_update_shift_schedule(posting_rule, &run_at)
+                        .await?;
+
+                    format!(
+                        "Перенесено на {}: {} ({})",
+                        next_date.format("%d.%m.%Y"),
+                        period.description(),
+                        calendar.name
+                    )
+                }
+                None => format!(
+                    "Пропущено, нет доступного дня для переноса: {} ({})",
+                    period.description(),
+                    calendar.name
+                ),
+            }
+        }
+    };
+
+    warn!(
+        posting_rule_id = %posting_rule.id(),
+        calendar_id = %calendar.id,
+        reason = %reason,
+        "Fire time falls into a blackout period"
+    );
+
+    let last_skip = PostingRuleSkip {
+        timestamp: Utc::now().timestamp(),
+        reason,
+    };
+
+    posting_rule_repository
+        .set_last_skip(posting_rule.id(), &last_skip)
+        .await?;
+
+    Ok(true)
+}
+
+// Checks the cron schedules of the rule, each in its own timezone, for a fire time on the given local day
+
+fn has_regular_fire(posting_rule: &PostingRule, day: NaiveDate) -> bool {
+    let tz = match date::parse_timezone(posting_rule.timezone()) {
+        Ok(tz) => tz,
+        Err(_) => return false,
+    };
+
+    // Fire times are searched after the given instant, so the search starts a second before midnight
+
+    let search_start = match day
+        .and_hms_opt(0, 0, 0)
+        .and_then(|day_start| day_start.and_local_timezone(tz).earliest())
+    {
+        Some(day_start) => day_start.with_timezone(&Utc) - Duration::seconds(1),
+        None => return false,
+    };
+
+    let schedules = match posting_rule.schedules() {
+        [] if posting_rule.is_one_shot() => vec![],
+        [] => vec![(posting_rule.schedule(), tz)],
+        entries => entries
+            .iter()
+            .map(|entry| {
+                let entry_tz = entry
+                    .timezone
+                    .as_deref()
+                    .and_then(|timezone| date::parse_timezone(timezone).ok())
+                    .unwrap_or(tz);
+
+                (entry.schedule.as_str(), entry_tz)
+            })
+            .collect(),
+    };
+
+    schedules.into_iter().any(|(schedule, schedule_tz)| {
+        CronExpression::parse(schedule)
+            .ok()
+            .and_then(|expression| expression.next_fire_time(search_start, schedule_tz))
+            .is_some_and(|fire_time| fire_time.with_timezone(&tz).date_naive() == day)
+    })
+}
@@ -1,5 +1,9 @@
-use crate::REPLACEMENTS;
 use crate::TelegramBotClient;
+use crate::blackout;
+use crate::poll_close;
+use crate::post_edit;
+use crate::queue;
+use crate::text_variants;
 use aws_sdk_lambda::Client as LambdaClient;
 use aws_sdk_lambda::primitives::Blob;
 use aws_sdk_lambda::types::InvocationType;
@@ -9,13 +13,18 @@ use telebot_shared::data::PollPostingRuleOptionSourcesNoResultsBehavior;
 use telebot_shared::{
     aws::DynamoDbClient,
     data::{
-        BotDataRepository, PollActionLog, PollActionLogRecord, PollActionLogRepository, PollPost,
-        PollPostingRule, PollPostingRuleActionLog, PollPostingRuleActionLogOutput,
+        BlackoutCalendarRepository, BotDataRepository, DocumentPost, MediaGroupPost,
+        MediaGroupPostItem, MediaGroupPostingRuleItemType, PhotoPost, PollActionLog,
+        PollActionLogRecord, PollActionLogRepository, PollPost, PollPostingRule,
+        PollPostingRuleActionLog, PollPostingRuleActionLogOutput,
         PollPostingRuleOptionIntersectionSource, PollPostingRuleOptionSource, Post, PostRepository,
-        PostingRule, PostingRuleRepository, PostingRuleTrait, SchedulerEvent, TextPost,
+        PostTrait, PostingRule, PostingRuleRepository, PostingRuleTrait, QueueItemRepository,
+        SchedulerEvent, SchedulerEventAction, TemplateVariable, TemplateVariableRepository,
+        TextPost, VideoPost,
     },
+    template::{self, TemplateContext},
 };
-use teloxide::types::{Message, Recipient};
+use teloxide::types::{Message, MessageId, Recipient};
 use tracing::{error, info, warn};
 
 // TODO: Split this handler into multiple smaller functions and move them into separate modules for better readability and maintainability
@@ -23,7 +32,11 @@ use tracing::{error, info, warn};
 pub async fn handle(event: LambdaEvent<SchedulerEvent>) -> Result<(), Error> {
     let (payload, context) = event.into_parts();
 
-    info!(posting_rule_id = %payload.posting_rule_id, "Received event");
+    info!(
+        posting_rule_id = %payload.posting_rule_id,
+        action = ?payload.action,
+        "Received event"
+    );
 
     let db = DynamoDbClient::new().await;
 
@@ -51,14 +64,6 @@ pub async fn handle(event: LambdaEvent<SchedulerEvent>) -> Result<(), Error> {
         return Ok(());
     }
 
-    if !posting_rule.is_active() {
-        warn!(
-            posting_rule_id = %posting_rule.id(),
-            "Posting rule is not active, skipping"
-        );
-        return Ok(());
-    }
-
     let bot_data_repository = BotDataRepository::new(&db).await?;
 
     let bot_data = match bot_data_repository.get(posting_rule.bot_id()).await? {
@@ -80,6 +85,95 @@ pub async fn handle(event: LambdaEvent<SchedulerEvent>) -> Result<(), Error> {
     let post_repository = PostRepository::new(db.client.clone()).await?;
     let poll_action_log_repository = PollActionLogRepository::new(db.client.clone()).await?;
 
+    let template_variable_repository = TemplateVariableRepository::new(db.client.clone()).await?;
+
+    let template_variables = TemplateVariable::values_for_chat(
+        &template_variable_repository
+            .get_by_bot(posting_rule.bot_id())
+            .await?,
+        posting_rule.chat_id().0,
+    );
+
+    if payload.action == SchedulerEventAction::EditLatestPost {
+        post_edit::edit_latest_post(&bot, &posting_rule, &post_repository, &template_variables)
+            .await?;
+
+        return Ok(());
+    }
+
+    if payload.action == SchedulerEventAction::ClosePoll {
+        match &posting_rule {
+            PostingRule::Poll(poll_posting_rule) => {
+                poll_close::close_poll(
+                    &bot,
+                    poll_posting_rule,
+                    &post_repository,
+                    &poll_action_log_repository,
+                    &template_variables,
+                )
+                .await?;
+            }
+            _ => {
+                warn!(
+                    posting_rule_id = %posting_rule.id(),
+                    "Posting rule is not a poll, skipping closing"
+                );
+            }
+        }
+
+        return Ok(());
+    }
+
+    // Only posting depends on the rule being active, a poll posted by a rule that has been deactivated
+    // or completed since must still be closed and its latest post kept in sync
+
+    if !posting_rule.is_active() {
+        warn!(
+            posting_rule_id = %posting_rule.id(),
+            "Posting rule is not active, skipping"
+        );
+        return Ok(());
+    }
+
+    if !posting_rule.is_within_dates(payload.scheduled_at().timestamp()) {
+        warn!(
+            posting_rule_id = %posting_rule.id(),
+            start_date = ?posting_rule.start_date(),
+            end_date = ?posting_rule.end_date(),
+            "Posting rule is outside of its start and end dates, skipping"
+        );
+        return Ok(());
+    }
+
+    let undefined_variables = posting_rule.undefined_variables(&template_variables);
+
+    if !undefined_variables.is_empty() {
+        warn!(
+            posting_rule_id = %posting_rule.id(),
+            issues = ?undefined_variables,
+            "Posting rule uses undefined variables, skipping"
+        );
+        return Ok(());
+    }
+
+    let blackout_calendar_repository = BlackoutCalendarRepository::new(db.client.clone()).await?;
+
+    if blackout::apply_blackout(
+        &posting_rule,
+        &posting_rule_repository,
+        &blackout_calendar_repository,
+        payload.scheduled_at(),
+        &context.invoked_function_arn,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let template_context = TemplateContext::new(payload.scheduled_at(), posting_rule.timezone())?
+        .with_variables(template_variables)
+        .with_locale(posting_rule.locale());
+
     post_message(
         &bot,
         &posting_rule,
@@ -87,20 +181,32 @@ pub async fn handle(event: LambdaEvent<SchedulerEvent>) -> Result<(), Error> {
         &poll_action_log_repository,
         &db,
         &context.invoked_function_arn,
+        &template_context,
     )
     .await?;
 
     info!(post_id = %posting_rule.id(), "Posting completed successfully");
 
+    if posting_rule.is_one_shot() {
+        posting_rule_repository
+            .set_completed(posting_rule.id(), chrono::Utc::now().timestamp())
+            .await?;
+
+        info!(
+            posting_rule_id = %posting_rule.id(),
+            "One-shot posting rule completed and deactivated"
+        );
+    }
+
     Ok(())
 }
 
-fn replace_variables(text: &str) -> String {
-    let mut result = text.to_string();
-    for (key, func) in REPLACEMENTS.iter() {
-        result = result.replace(key, &func());
-    }
-    result
+pub(crate) fn replace_variables(text: &str, context: &TemplateContext) -> String {
+    template::render(text, context)
+}
+
+pub(crate) fn render_caption(caption: Option<&str>, context: &TemplateContext) -> Option<String> {
+    caption.map(|text| replace_variables(text, context))
 }
 
 async fn post_message(
@@ -110,18 +216,22 @@ async fn post_message(
     poll_action_log_repository: &PollActionLogRepository,
     db: &DynamoDbClient,
     function_arn: &str,
+    template_context: &TemplateContext,
 ) -> Result<(), anyhow::Error> {
     let chat_id: Recipient = posting_rule.chat_id().into();
     let topic_id = posting_rule.topic_id();
 
     match posting_rule {
         PostingRule::Text(text_posting_rule) => {
-            let text = replace_variables(&text_posting_rule.content.text);
-            let message = bot.send_text(chat_id.clone(), topic_id, &text).await?;
+            let variant_index = text_variants::select_variant(text_posting_rule);
 
-            if text_posting_rule.should_pin() {
-                bot.pin_message(chat_id.clone(), message.id).await?;
-            }
+            let text = text_posting_rule
+                .content
+                .text_for_variant(variant_index)
+                .unwrap_or_default();
+
+            let text = replace_variables(text, template_context);
+            let message = bot.send_text(chat_id.clone(), topic_id, &text).await?;
 
             info!("Message sent successfully, saving post to repository");
 
@@ -130,14 +240,271 @@ async fn post_message(
                 message.id.0,
                 message.date.timestamp(),
                 &text,
+                variant_index,
+            );
+
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Text(text_post),
+                template_context,
+            )
+            .await?;
+
+            if let Some(indices) = variant_index
+                .and_then(|index| text_variants::recent_variant_indices(text_posting_rule, index))
+            {
+                let posting_rule_repository = PostingRuleRepository::new(db.client.clone()).await?;
+
+                posting_rule_repository
+                    .set_recent_variant_indices(text_posting_rule.id(), &indices)
+                    .await?;
+            }
+
+            Ok(())
+        }
+        PostingRule::Photo(photo_posting_rule) => {
+            let caption = render_caption(
+                photo_posting_rule.content.caption.as_deref(),
+                template_context,
+            );
+
+            let message = bot
+                .send_photo(
+                    chat_id.clone(),
+                    topic_id,
+                    &photo_posting_rule.content.media,
+                    caption.as_deref(),
+                )
+                .await?;
+
+            info!("Photo sent successfully, saving post to repository");
+
+            let file_id = get_media_file_id(&message, MediaGroupPostingRuleItemType::Photo);
+
+            let photo_post = PhotoPost::new(
+                photo_posting_rule,
+                message.id.0,
+                message.date.timestamp(),
+                &file_id,
+                caption.as_deref(),
+            );
+
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Photo(photo_post),
+                template_context,
+            )
+            .await?;
+
+            Ok(())
+        }
+        PostingRule::Video(video_posting_rule) => {
+            let caption = render_caption(
+                video_posting_rule.content.caption.as_deref(),
+                template_context,
+            );
+
+            let message = bot
+                .send_video(
+                    chat_id.clone(),
+                    topic_id,
+                    &video_posting_rule.content.media,
+                    caption.as_deref(),
+                )
+                .await?;
+
+            info!("Video sent successfully, saving post to repository");
+
+            let file_id = get_media_file_id(&message, MediaGroupPostingRuleItemType::Video);
+
+            let video_post = VideoPost::new(
+                video_posting_rule,
+                message.id.0,
+                message.date.timestamp(),
+                &file_id,
+                caption.as_deref(),
             );
 
-            post_repository.put(&Post::Text(text_post)).await?;
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Video(video_post),
+                template_context,
+            )
+            .await?;
+
+            Ok(())
+        }
+        PostingRule::Document(document_posting_rule) => {
+            let caption = render_caption(
+                document_posting_rule.content.caption.as_deref(),
+                template_context,
+            );
+
+            let message = bot
+                .send_document(
+                    chat_id.clone(),
+                    topic_id,
+                    &document_posting_rule.content.media,
+                    caption.as_deref(),
+                )
+                .await?;
+
+            info!("Document sent successfully, saving post to repository");
+
+            let file_id = get_media_file_id(&message, MediaGroupPostingRuleItemType::Document);
+
+            let document_post = DocumentPost::new(
+                document_posting_rule,
+                message.id.0,
+                message.date.timestamp(),
+                &file_id,
+                caption.as_deref(),
+            );
+
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Document(document_post),
+                template_context,
+            )
+            .await?;
+
+            Ok(())
+        }
+        PostingRule::MediaGroup(media_group_posting_rule) => {
+            let items = media_group_posting_rule
+                .content
+                .items
+                .iter()
+                .map(|item| {
+                    let mut item = item.clone();
+                    item.caption = render_caption(item.caption.as_deref(), template_context);
+                    item
+                })
+                .collect::<Vec<_>>();
+
+            let messages = bot
+                .send_media_group(chat_id.clone(), topic_id, &items)
+                .await?;
+
+            let first_message = match messages.first() {
+                Some(message) => message,
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "Telegram returned no messages for media group of posting rule {}",
+                        media_group_posting_rule.id()
+                    ));
+                }
+            };
+
+            info!("Media group sent successfully, saving post to repository");
+
+            let message_ids = messages
+                .iter()
+                .map(|message| message.id.0)
+                .collect::<Vec<_>>();
+
+            let post_items = items
+                .iter()
+                .zip(messages.iter())
+                .map(|(item, message)| MediaGroupPostItem {
+                    media_type: item.media_type,
+                    file_id: get_media_file_id(message, item.media_type),
+                    caption: item.caption.clone(),
+                })
+                .collect::<Vec<_>>();
+
+            let media_group_post = MediaGroupPost::new(
+                media_group_posting_rule,
+                &message_ids,
+                first_message.date.timestamp(),
+                post_items,
+            );
+
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                first_message.id,
+                Post::MediaGroup(media_group_post),
+                template_context,
+            )
+            .await?;
+
+            Ok(())
+        }
+        PostingRule::Queue(queue_posting_rule) => {
+            let queue_item_repository = QueueItemRepository::new(db.client.clone()).await?;
+
+            let queue_item = match queue_item_repository
+                .claim_first(queue_posting_rule.id())
+                .await?
+            {
+                Some(queue_item) => queue_item,
+                None => {
+                    let posting_rule_repository =
+                        PostingRuleRepository::new(db.client.clone()).await?;
+
+                    return queue::handle_empty_queue(
+                        bot,
+                        queue_posting_rule,
+                        &posting_rule_repository,
+                    )
+                    .await;
+                }
+            };
+
+            // The item is claimed before sending so that overlapping runs cannot post it twice,
+            // it goes back to the queue if Telegram rejects it
+
+            let text = replace_variables(&queue_item.text, template_context);
+
+            let message = match bot.send_text(chat_id.clone(), topic_id, &text).await {
+                Ok(message) => message,
+                Err(err) => {
+                    queue_item_repository.restore(&queue_item).await?;
+                    return Err(err);
+                }
+            };
+
+            info!(
+                position = queue_item.position,
+                "Queue item sent successfully, saving post to repository"
+            );
+
+            let text_post = TextPost::new(
+                queue_posting_rule,
+                message.id.0,
+                message.date.timestamp(),
+                &text,
+                None,
+            );
+
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Text(text_post),
+                template_context,
+            )
+            .await?;
 
             Ok(())
         }
         PostingRule::Poll(poll_posting_rule) => {
-            let question = replace_variables(&poll_posting_rule.content.question);
+            let question = replace_variables(&poll_posting_rule.content.question, template_context);
 
             let mut options: Vec<String> = vec![];
 
@@ -201,13 +568,15 @@ async fn post_message(
             }
 
             let message = bot
-                .send_poll(chat_id.clone(), topic_id, &question, &options[..])
+                .send_poll(
+                    chat_id.clone(),
+                    topic_id,
+                    &question,
+                    &options[..],
+                    &poll_posting_rule.content,
+                )
                 .await?;
 
-            if poll_posting_rule.should_pin() {
-                bot.pin_message(chat_id.clone(), message.id).await?;
-            }
-
             info!("Poll sent successfully, saving post to repository");
 
             let poll_post = PollPost::new(
@@ -217,7 +586,15 @@ async fn post_message(
                 &question,
                 &options,
             );
-            post_repository.put(&Post::Poll(poll_post)).await?;
+            finish_post(
+                bot,
+                posting_rule,
+                post_repository,
+                message.id,
+                Post::Poll(poll_post),
+                template_context,
+            )
+            .await?;
 
             info!("Post saved successfully, checking if poll action log is enabled");
 
@@ -259,6 +636,23 @@ async fn post_message(
     }
 }
 
+fn get_media_file_id(message: &Message, media_type: MediaGroupPostingRuleItemType) -> String {
+    let file_id = match media_type {
+        MediaGroupPostingRuleItemType::Photo => message
+            .photo()
+            .and_then(|photos| photos.last())
+            .map(|photo| photo.file.id.to_string()),
+        MediaGroupPostingRuleItemType::Video => {
+            message.video().map(|video| video.file.id.to_string())
+        }
+        MediaGroupPostingRuleItemType::Document => message
+            .document()
+            .map(|document| document.file.id.to_string()),
+    };
+
+    file_id.unwrap_or_default()
+}
+
 async fn invoke_fallback_posting_rule(
     fallback_posting_rule_id: &str,
     function_arn: &str,
@@ -268,6 +662,8 @@ async fn invoke_fallback_posting_rule(
 
     let payload = SchedulerEvent {
         posting_rule_id: fallback_posting_rule_id.to_string(),
+        action: SchedulerEventAction::Post,
+        scheduled_time: None,
     };
 
     // TODO: Deal with different error handling
@@ -322,6 +718,83 @@ async fn post_poll_action_log_message(
     Ok(message)
 }
 
+// Every sent post is pinned before it is saved, see pin_message. The scheduled time is kept so that
+// edits of the post render its content again at the time it was scheduled for
+
+async fn finish_post(
+    bot: &TelegramBotClient,
+    posting_rule: &PostingRule,
+    post_repository: &PostRepository,
+    message_id: MessageId,
+    post: Post,
+    template_context: &TemplateContext,
+) -> Result<(), anyhow::Error> {
+    if posting_rule.should_pin() {
+        pin_message(bot, posting_rule, post_repository, message_id).await?;
+    }
+
+    let mut post = post;
+    post.set_scheduled_at(template_context.fire_time.timestamp());
+
+    post_repository.put(&post).await
+}
+
+// Must run before the new post is saved, otherwise the most recent post is the new one
+
+async fn pin_message(
+    bot: &TelegramBotClient,
+    posting_rule: &PostingRule,
+    post_repository: &PostRepository,
+    message_id: MessageId,
+) -> Result<(), anyhow::Error> {
+    bot.pin_message(posting_rule.chat_id().into(), message_id)
+        .await?;
+
+    if !posting_rule.unpin_previous() {
+        return Ok(());
+    }
+
+    let mut previous_post = match post_repository
+        .get_most_recent_by_posting_rule(posting_rule.id())
+        .await?
+    {
+        Some(post) if post.is_pinned() => post,
+        _ => {
+            info!(
+                posting_rule_id = %posting_rule.id(),
+                "No pinned previous post found, nothing to unpin"
+            );
+            return Ok(());
+        }
+    };
+
+    // The previous message may have been deleted or unpinned by hand, this must not fail the new post
+
+    if let Err(err) = bot
+        .unpin_message(previous_post.chat_id().into(), previous_post.message_id())
+        .await
+    {
+        warn!(
+            posting_rule_id = %posting_rule.id(),
+            message_id = previous_post.base().message_id,
+            error = %err,
+            "Failed to unpin previous post"
+        );
+        return Ok(());
+    }
+
+    previous_post.set_pinned(false);
+    post_repository.put(&previous_post).await?;
+
+    info!(
+        posting_rule_id = %posting_rule.id(),
+        message_id = previous_post.base().message_id,
+        "Previous post unpinned"
+    );
+
+    Ok(())
+}
+
 async fn create_poll_action_log(
     message: Message,
     poll_action_log_message: Message,
@@ -375,7 +848,7 @@ async fn get_intersection_sourced_poll_options(
         .await?
     {
         Some(Post::Poll(poll_post)) => poll_post,
-        Some(Post::Text(_)) => {
+        Some(_) => {
             error!(
                 posting_rule_id = %source.source_posting_rule_id,
                 "Found post is not a poll"
@@ -423,21 +896,9 @@ async fn get_intersection_sourced_poll_options(
 
     // Find the latest record per actor that voted for the target option
     let target_option_voters: HashMap<u64, PollActionLogRecord> = action_log
-        .records
-        .iter()
-        .fold(HashMap::new(), |mut latest_by_actor, record| {
-            latest_by_actor
-                .entry(record.actor_id)
-                .and_modify(|existing: &mut PollActionLogRecord| {
-                    if record.timestamp > existing.timestamp {
-                        *existing = record.clone();
-                    }
-                })
-                .or_insert_with(|| record.clone());
-            latest_by_actor
-        })
+        .latest_records_by_actor()
         .into_iter()
-        .filter(|(_, record)| record.option_id == Some(source.target_option_id))
+        .filter(|(_, record)| record.has_option(source.target_option_id))
         .collect();
 
     if target_option_voters.is_empty() {
@@ -1,7 +1,9 @@
-pub mod date_utils;
+pub mod blackout;
 pub mod handler;
-pub mod replacements;
+pub mod poll_close;
+pub mod post_edit;
+pub mod queue;
 pub mod telegram;
+pub mod text_variants;
 
-pub use replacements::REPLACEMENTS;
 pub use telegram::TelegramBotClient;
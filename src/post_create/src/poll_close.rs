@@ -0,0 +1,205 @@
+use crate::{TelegramBotClient, handler::replace_variables};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use telebot_shared::{
+    data::{
+        PollActionLogRepository, PollPost, PollPostOptionResult, PollPostingRule,
+        PollPostingRuleResultsSummaryTarget, Post, PostRepository, PostTrait, PostingRuleTrait,
+    },
+    template::TemplateContext,
+};
+use teloxide::{
+    types::{Poll, Recipient},
+    utils::html,
+};
+use tracing::{info, warn};
+
+pub async fn close_poll(
+    bot: &TelegramBotClient,
+    poll_posting_rule: &PollPostingRule,
+    post_repository: &PostRepository,
+    poll_action_log_repository: &PollActionLogRepository,
+    template_variables: &HashMap<String, String>,
+) -> Result<(), anyhow::Error> {
+    let mut poll_post = match post_repository
+        .get_most_recent_by_posting_rule(poll_posting_rule.id())
+        .await?
+    {
+        Some(Post::Poll(poll_post)) => poll_post,
+        Some(_) => {
+            warn!(
+                posting_rule_id = %poll_posting_rule.id(),
+                "Most recent post is not a poll, skipping closing"
+            );
+            return Ok(());
+        }
+        None => {
+            warn!(
+                posting_rule_id = %poll_posting_rule.id(),
+                "No post found for posting rule, skipping closing"
+            );
+            return Ok(());
+        }
+    };
+
+    if poll_post.is_closed() {
+        info!(
+            posting_rule_id = %poll_posting_rule.id(),
+            message_id = poll_post.base.message_id,
+            "Poll is already closed, skipping"
+        );
+        return Ok(());
+    }
+
+    // A poll that Telegram has already closed, by its open period or by hand, returns no final tally,
+    // the results are then left empty and the summary is still sent
+
+    let chat_id: Recipient = poll_post.chat_id().into();
+    let poll = bot.stop_poll(chat_id, poll_post.message_id()).await?;
+
+    let results = match &poll {
+        Some(poll) => poll
+            .options
+            .iter()
+            .map(|option| PollPostOptionResult {
+                text: option.text.clone(),
+                voter_count: option.voter_count,
+            })
+            .collect::<Vec<_>>(),
+        None => {
+            info!(
+                posting_rule_id = %poll_posting_rule.id(),
+                message_id = poll_post.base.message_id,
+                "Poll has already been closed in Telegram, final tally is not available"
+            );
+            vec![]
+        }
+    };
+
+    poll_post.close(chrono::Utc::now().timestamp(), results);
+
+    post_repository.put(&Post::Poll(poll_post.clone())).await?;
+
+    info!(
+        posting_rule_id = %poll_posting_rule.id(),
+        total_voter_count = poll.as_ref().map(|poll| poll.total_voter_count),
+        "Poll closed and final tally saved"
+    );
+
+    if poll_posting_rule.results_summary.is_some() {
+        send_results_summary(
+            bot,
+            poll_posting_rule,
+            &poll_post,
+            poll.as_ref(),
+            poll_action_log_repository,
+            template_variables,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn send_results_summary(
+    bot: &TelegramBotClient,
+    poll_posting_rule: &PollPostingRule,
+    poll_post: &PollPost,
+    poll: Option<&Poll>,
+    poll_action_log_repository: &PollActionLogRepository,
+    template_variables: &HashMap<String, String>,
+) -> Result<(), anyhow::Error> {
+    let results_summary = match &poll_posting_rule.results_summary {
+        Some(results_summary) => results_summary,
+        None => return Ok(()),
+    };
+
+    let (chat_id, topic_id) = match results_summary.target {
+        PollPostingRuleResultsSummaryTarget::SameChat => {
+            (poll_posting_rule.chat_id(), poll_posting_rule.topic_id())
+        }
+        PollPostingRuleResultsSummaryTarget::ActionLogChat => match &poll_posting_rule.action_log {
+            Some(action_log) => (action_log.chat_id(), action_log.topic_id()),
+            None => {
+                warn!(
+                    posting_rule_id = %poll_posting_rule.id(),
+                    "Action log is not configured, skipping results summary"
+                );
+                return Ok(());
+            }
+        },
+    };
+
+    // Voter names are only known for non-anonymous polls with an action log
+
+    let action_log = poll_action_log_repository
+        .get_by_chat_and_message(poll_post.base.chat_id, poll_post.base.message_id)
+        .await?;
+
+    let latest_records = action_log
+        .map(|action_log| action_log.latest_records_by_actor())
+        .unwrap_or_default();
+
+    // Poll texts and voter names are plain text and must not be taken for markup
+
+    let mut text = format!(
+        "<b>Итоги голосования: {}</b>",
+        html::escape(poll_posting_rule.name())
+    );
+
+    if let Some(header) = &results_summary.header {
+        let posted_at = DateTime::from_timestamp(poll_post.timestamp(), 0).unwrap_or_else(Utc::now);
+        let template_context = TemplateContext::new(posted_at, poll_posting_rule.timezone())?
+            .with_variables(template_variables.clone())
+            .with_locale(poll_posting_rule.locale());
+
+        text.push_str(&format!(
+            "\n\n{}",
+            replace_variables(header, &template_context)
+        ));
+    }
+
+    text.push_str(&format!(
+        "\n\n<i>{}</i>\n",
+        html::escape(&poll_post.content.question)
+    ));
+
+    for (i, option) in poll_post.content.options.iter().enumerate() {
+        let voter_count = poll
+            .and_then(|poll| poll.options.get(i))
+            .map_or(String::new(), |option| format!(": {}", option.voter_count));
+
+        text.push_str(&format!("\n<b>{}</b>{}", html::escape(option), voter_count));
+
+        let mut voters = latest_records
+            .values()
+            .filter(|record| !record.is_revoked() && record.has_option(i as i32))
+            .collect::<Vec<_>>();
+
+        voters.sort_by_key(|record| record.timestamp);
+
+        for voter in voters {
+            text.push_str(&format!("\n  • {}", html::escape(&voter.actor_name())));
+        }
+    }
+
+    match poll {
+        Some(poll) => text.push_str(&format!(
+            "\n\nВсего проголосовало: {}",
+            poll.total_voter_count
+        )),
+        None => text.push_str(
+            "\n\n<i>Опрос был закрыт в Telegram раньше, количество голосов недоступно</i>",
+        ),
+    }
+
+    let chat_id: Recipient = chat_id.into();
+    bot.send_text(chat_id, topic_id, &text).await?;
+
+    info!(
+        posting_rule_id = %poll_posting_rule.id(),
+        "Poll results summary sent"
+    );
+
+    Ok(())
+}
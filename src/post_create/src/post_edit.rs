@@ -0,0 +1,167 @@
+use crate::{
+    TelegramBotClient,
+    handler::{render_caption, replace_variables},
+};
+use chrono::DateTime;
+use std::collections::HashMap;
+use telebot_shared::{
+    data::{Post, PostRepository, PostTrait, PostingRule, PostingRuleTrait},
+    template::TemplateContext,
+};
+use teloxide::types::{MessageId, Recipient};
+use tracing::{info, warn};
+
+pub async fn edit_latest_post(
+    bot: &TelegramBotClient,
+    posting_rule: &PostingRule,
+    post_repository: &PostRepository,
+    template_variables: &HashMap<String, String>,
+) -> Result<(), anyhow::Error> {
+    if !posting_rule.propagate_edits() {
+        info!(
+            posting_rule_id = %posting_rule.id(),
+            "Edit propagation is disabled, skipping"
+        );
+        return Ok(());
+    }
+
+    let mut post = match post_repository
+        .get_most_recent_by_posting_rule(posting_rule.id())
+        .await?
+    {
+        Some(post) => post,
+        None => {
+            info!(
+                posting_rule_id = %posting_rule.id(),
+                "No post found for posting rule, nothing to edit"
+            );
+            return Ok(());
+        }
+    };
+
+    let chat_id: Recipient = post.chat_id().into();
+    let message_id = post.message_id();
+
+    // Variables keep the values they had at the time the post was scheduled for, posts saved
+    // before that time was stored cannot be rendered the same way and are left as they are
+
+    let scheduled_at = match post
+        .scheduled_at()
+        .and_then(|scheduled_at| DateTime::from_timestamp(scheduled_at, 0))
+    {
+        Some(scheduled_at) => scheduled_at,
+        None => {
+            warn!(
+                posting_rule_id = %posting_rule.id(),
+                message_id = message_id.0,
+                "Most recent post has no scheduled time, skipping"
+            );
+            return Ok(());
+        }
+    };
+
+    let template_context = TemplateContext::new(scheduled_at, posting_rule.timezone())?
+        .with_variables(template_variables.clone())
+        .with_locale(posting_rule.locale());
+
+    match (posting_rule, &mut post) {
+        (PostingRule::Text(text_posting_rule), Post::Text(text_post)) => {
+            let text = match text_posting_rule
+                .content
+                .text_for_variant(text_post.content.variant_index)
+            {
+                Some(text) => replace_variables(text, &template_context),
+                None => {
+                    info!(
+                        posting_rule_id = %posting_rule.id(),
+                        "Posted variant no longer exists, nothing to edit"
+                    );
+                    return Ok(());
+                }
+            };
+
+            bot.edit_text(chat_id, message_id, &text).await?;
+            text_post.content.text = text;
+        }
+        (PostingRule::Photo(photo_posting_rule), Post::Photo(photo_post)) => {
+            let caption = render_caption(
+                photo_posting_rule.content.caption.as_deref(),
+                &template_context,
+            );
+
+            bot.edit_caption(chat_id, message_id, caption.as_deref())
+                .await?;
+            photo_post.content.caption = caption;
+        }
+        (PostingRule::Video(video_posting_rule), Post::Video(video_post)) => {
+            let caption = render_caption(
+                video_posting_rule.content.caption.as_deref(),
+                &template_context,
+            );
+
+            bot.edit_caption(chat_id, message_id, caption.as_deref())
+                .await?;
+            video_post.content.caption = caption;
+        }
+        (PostingRule::Document(document_posting_rule), Post::Document(document_post)) => {
+            let caption = render_caption(
+                document_posting_rule.content.caption.as_deref(),
+                &template_context,
+            );
+
+            bot.edit_caption(chat_id, message_id, caption.as_deref())
+                .await?;
+            document_post.content.caption = caption;
+        }
+        (PostingRule::MediaGroup(media_group_posting_rule), Post::MediaGroup(media_group_post)) => {
+            if media_group_posting_rule.content.items.len() != media_group_post.content.items.len()
+            {
+                warn!(
+                    posting_rule_id = %posting_rule.id(),
+                    "Media group items were added or removed, only captions can be edited, skipping"
+                );
+                return Ok(());
+            }
+
+            let message_ids = media_group_post.content.message_ids.clone();
+
+            for ((rule_item, post_item), message_id) in media_group_posting_rule
+                .content
+                .items
+                .iter()
+                .zip(media_group_post.content.items.iter_mut())
+                .zip(message_ids)
+            {
+                let caption = render_caption(rule_item.caption.as_deref(), &template_context);
+
+                bot.edit_caption(chat_id.clone(), MessageId(message_id), caption.as_deref())
+                    .await?;
+                post_item.caption = caption;
+            }
+        }
+        (PostingRule::Poll(_), Post::Poll(_)) => {
+            warn!(
+                posting_rule_id = %posting_rule.id(),
+                "Telegram does not allow editing sent polls, skipping"
+            );
+            return Ok(());
+        }
+        _ => {
+            warn!(
+                posting_rule_id = %posting_rule.id(),
+                "Most recent post type does not match the posting rule, skipping"
+            );
+            return Ok(());
+        }
+    }
+
+    post_repository.put(&post).await?;
+
+    info!(
+        posting_rule_id = %posting_rule.id(),
+        message_id = message_id.0,
+        "Most recent post edited"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::TelegramBotClient;
+use telebot_shared::data::{
+    PostingRuleRepository, PostingRuleTrait, QueuePostingRule, QueuePostingRuleEmptyQueueBehavior,
+};
+use teloxide::types::{ChatId, Recipient};
+use tracing::info;
+
+// Called when a queue rule fires with no items left, nothing is posted in this case
+
+pub async fn handle_empty_queue(
+    bot: &TelegramBotClient,
+    queue_posting_rule: &QueuePostingRule,
+    posting_rule_repository: &PostingRuleRepository,
+) -> Result<(), anyhow::Error> {
+    match queue_posting_rule.content.empty_queue_behavior {
+        QueuePostingRuleEmptyQueueBehavior::Deactivate => {
+            posting_rule_repository
+                .set_active(queue_posting_rule.id(), false)
+                .await?;
+
+            info!(
+                posting_rule_id = %queue_posting_rule.id(),
+                "Queue is empty, posting rule deactivated"
+            );
+        }
+        QueuePostingRuleEmptyQueueBehavior::NotifyAdmins { chat_id } => {
+            let chat_id: Recipient = ChatId(chat_id).into();
+            let text = format!(
+                "📭 Очередь правила <b>{}</b> пуста, публикация пропущена. Добавьте новые записи командой <code>/enqueue {} текст</code>",
+                queue_posting_rule.name(),
+                queue_posting_rule.id()
+            );
+
+            bot.send_text(chat_id, None, &text).await?;
+
+            info!(
+                posting_rule_id = %queue_posting_rule.id(),
+                "Queue is empty, admins notified"
+            );
+        }
+    }
+
+    Ok(())
+}
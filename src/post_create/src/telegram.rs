@@ -1,8 +1,15 @@
-use telebot_shared::data::BotData;
+use telebot_shared::data::{
+    BotData, MediaGroupPostingRuleItem, MediaGroupPostingRuleItemType, PollPostingRuleContent,
+    PollPostingRulePollType, PostingRuleMedia,
+};
 use teloxide::{
+    ApiError, RequestError,
     payloads::SendMessageSetters,
     prelude::*,
-    types::{InputPollOption, MessageId, ParseMode, Recipient, ThreadId},
+    types::{
+        FileId, InputFile, InputMedia, InputMediaDocument, InputMediaPhoto, InputMediaVideo,
+        InputPollOption, MessageId, ParseMode, Poll, PollType, Recipient, ThreadId,
+    },
 };
 
 pub struct TelegramBotClient {
@@ -45,6 +52,7 @@ impl TelegramBotClient {
         topic_id: Option<MessageId>,
         question: &str,
         options: &[String],
+        settings: &PollPostingRuleContent,
     ) -> Result<Message, anyhow::Error> {
         let poll_options: Vec<InputPollOption> = options
             .iter()
@@ -54,7 +62,111 @@ impl TelegramBotClient {
         let mut request = self
             .bot
             .send_poll(chat_id, question, poll_options)
-            .is_anonymous(false);
+            .is_anonymous(settings.is_anonymous);
+
+        match &settings.poll_type {
+            Some(PollPostingRulePollType::Quiz {
+                correct_option_id,
+                explanation,
+            }) => {
+                request = request
+                    .type_(PollType::Quiz)
+                    .correct_option_id(*correct_option_id);
+
+                if let Some(explanation) = explanation {
+                    request = request
+                        .explanation(explanation)
+                        .explanation_parse_mode(ParseMode::Html);
+                }
+            }
+            Some(PollPostingRulePollType::Regular) | None => {
+                request = request
+                    .type_(PollType::Regular)
+                    .allows_multiple_answers(settings.allows_multiple_answers);
+            }
+        }
+
+        if let Some(open_period) = settings.open_period {
+            request = request.open_period(open_period);
+        }
+
+        if let Some(topic_id) = topic_id {
+            let thread_id = ThreadId(topic_id);
+            request = request.message_thread_id(thread_id);
+        }
+
+        let message = request.await?;
+
+        Ok(message)
+    }
+
+    pub async fn send_photo(
+        &self,
+        chat_id: Recipient,
+        topic_id: Option<MessageId>,
+        media: &PostingRuleMedia,
+        caption: Option<&str>,
+    ) -> Result<Message, anyhow::Error> {
+        let mut request = self
+            .bot
+            .send_photo(chat_id, Self::input_file(media)?)
+            .parse_mode(ParseMode::Html);
+
+        if let Some(caption) = caption {
+            request = request.caption(caption);
+        }
+
+        if let Some(topic_id) = topic_id {
+            let thread_id = ThreadId(topic_id);
+            request = request.message_thread_id(thread_id);
+        }
+
+        let message = request.await?;
+
+        Ok(message)
+    }
+
+    pub async fn send_video(
+        &self,
+        chat_id: Recipient,
+        topic_id: Option<MessageId>,
+        media: &PostingRuleMedia,
+        caption: Option<&str>,
+    ) -> Result<Message, anyhow::Error> {
+        let mut request = self
+            .bot
+            .send_video(chat_id, Self::input_file(media)?)
+            .parse_mode(ParseMode::Html);
+
+        if let Some(caption) = caption {
+            request = request.caption(caption);
+        }
+
+        if let Some(topic_id) = topic_id {
+            let thread_id = ThreadId(topic_id);
+            request = request.message_thread_id(thread_id);
+        }
+
+        let message = request.await?;
+
+        Ok(message)
+    }
+
+    pub async fn send_document(
+        &self,
+        chat_id: Recipient,
+        topic_id: Option<MessageId>,
+        media: &PostingRuleMedia,
+        caption: Option<&str>,
+    ) -> Result<Message, anyhow::Error> {
+        let mut request = self
+            .bot
+            .send_document(chat_id, Self::input_file(media)?)
+            .parse_mode(ParseMode::Html);
+
+        if let Some(caption) = caption {
+            request = request.caption(caption);
+        }
 
         if let Some(topic_id) = topic_id {
             let thread_id = ThreadId(topic_id);
@@ -66,6 +178,43 @@ impl TelegramBotClient {
         Ok(message)
     }
 
+    pub async fn send_media_group(
+        &self,
+        chat_id: Recipient,
+        topic_id: Option<MessageId>,
+        items: &[MediaGroupPostingRuleItem],
+    ) -> Result<Vec<Message>, anyhow::Error> {
+        let media = items
+            .iter()
+            .map(Self::input_media)
+            .collect::<Result<Vec<InputMedia>, _>>()?;
+
+        let mut request = self.bot.send_media_group(chat_id, media);
+
+        if let Some(topic_id) = topic_id {
+            let thread_id = ThreadId(topic_id);
+            request = request.message_thread_id(thread_id);
+        }
+
+        let messages = request.await?;
+
+        Ok(messages)
+    }
+
+    // Returns None if the poll has already been closed, by its open period or by hand
+
+    pub async fn stop_poll(
+        &self,
+        chat_id: Recipient,
+        message_id: MessageId,
+    ) -> Result<Option<Poll>, anyhow::Error> {
+        match self.bot.stop_poll(chat_id, message_id).await {
+            Ok(poll) => Ok(Some(poll)),
+            Err(RequestError::Api(ApiError::PollHasAlreadyClosed)) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     pub async fn pin_message(
         &self,
         chat_id: Recipient,
@@ -78,4 +227,102 @@ impl TelegramBotClient {
 
         Ok(())
     }
+
+    pub async fn edit_text(
+        &self,
+        chat_id: Recipient,
+        message_id: MessageId,
+        text: &str,
+    ) -> Result<(), anyhow::Error> {
+        let result = self
+            .bot
+            .edit_message_text(chat_id, message_id, text)
+            .parse_mode(ParseMode::Html)
+            .await;
+
+        Self::ignore_not_modified(result.map(|_| ()))
+    }
+
+    pub async fn edit_caption(
+        &self,
+        chat_id: Recipient,
+        message_id: MessageId,
+        caption: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
+        let mut request = self
+            .bot
+            .edit_message_caption(chat_id, message_id)
+            .parse_mode(ParseMode::Html);
+
+        if let Some(caption) = caption {
+            request = request.caption(caption);
+        }
+
+        Self::ignore_not_modified(request.await.map(|_| ()))
+    }
+
+    pub async fn unpin_message(
+        &self,
+        chat_id: Recipient,
+        message_id: MessageId,
+    ) -> Result<(), anyhow::Error> {
+        self.bot
+            .unpin_chat_message(chat_id)
+            .message_id(message_id)
+            .await?;
+
+        Ok(())
+    }
+
+    // Telegram rejects edits that leave the message unchanged, which is not an error for us
+
+    fn ignore_not_modified(result: Result<(), RequestError>) -> Result<(), anyhow::Error> {
+        match result {
+            Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+            result => Ok(result?),
+        }
+    }
+
+    fn input_file(media: &PostingRuleMedia) -> Result<InputFile, anyhow::Error> {
+        match media {
+            PostingRuleMedia::FileId { file_id } => Ok(InputFile::file_id(FileId(file_id.clone()))),
+            PostingRuleMedia::Url { url } => Ok(InputFile::url(url.parse()?)),
+        }
+    }
+
+    fn input_media(item: &MediaGroupPostingRuleItem) -> Result<InputMedia, anyhow::Error> {
+        let input_file = Self::input_file(&item.media)?;
+
+        let input_media = match item.media_type {
+            MediaGroupPostingRuleItemType::Photo => {
+                let mut media = InputMediaPhoto::new(input_file).parse_mode(ParseMode::Html);
+
+                if let Some(caption) = &item.caption {
+                    media = media.caption(caption);
+                }
+
+                InputMedia::Photo(media)
+            }
+            MediaGroupPostingRuleItemType::Video => {
+                let mut media = InputMediaVideo::new(input_file).parse_mode(ParseMode::Html);
+
+                if let Some(caption) = &item.caption {
+                    media = media.caption(caption);
+                }
+
+                InputMedia::Video(media)
+            }
+            MediaGroupPostingRuleItemType::Document => {
+                let mut media = InputMediaDocument::new(input_file).parse_mode(ParseMode::Html);
+
+                if let Some(caption) = &item.caption {
+                    media = media.caption(caption);
+                }
+
+                InputMedia::Document(media)
+            }
+        };
+
+        Ok(input_media)
+    }
 }
@@ -0,0 +1,67 @@
+use telebot_shared::data::{TextPostingRule, TextPostingRuleVariantSelection};
+
+// Returns the index of the variant to post, rules without variants get None. The choice depends on
+// the indices stored on the rule rather than on previous posts, which may already have expired
+
+pub fn select_variant(text_posting_rule: &TextPostingRule) -> Option<usize> {
+    let content = &text_posting_rule.content;
+    let count = content.variants.len();
+
+    if count == 0 {
+        return None;
+    }
+
+    let recent_indices = &text_posting_rule.recent_variant_indices;
+
+    let index = match content.variant_selection {
+        TextPostingRuleVariantSelection::RoundRobin => recent_indices
+            .first()
+            .map_or(0, |last_index| (last_index + 1) % count),
+        TextPostingRuleVariantSelection::Random => fastrand::usize(..count),
+        TextPostingRuleVariantSelection::RandomWithoutRepeat { .. } => {
+            let history_size = history_size(text_posting_rule);
+
+            let candidates = (0..count)
+                .filter(|index| !recent_indices.iter().take(history_size).any(|i| i == index))
+                .collect::<Vec<_>>();
+
+            fastrand::choice(candidates).unwrap_or_else(|| fastrand::usize(..count))
+        }
+    };
+
+    Some(index)
+}
+
+// Returns the indices to store on the rule after posting the given variant, or None if the selection
+// does not depend on them
+
+pub fn recent_variant_indices(
+    text_posting_rule: &TextPostingRule,
+    index: usize,
+) -> Option<Vec<usize>> {
+    let history_size = history_size(text_posting_rule);
+
+    if history_size == 0 {
+        return None;
+    }
+
+    let mut indices = vec![index];
+    indices.extend(text_posting_rule.recent_variant_indices.iter().copied());
+    indices.truncate(history_size);
+
+    Some(indices)
+}
+
+// At least one variant has to stay available, so no more than count - 1 recent ones are excluded
+
+fn history_size(text_posting_rule: &TextPostingRule) -> usize {
+    let count = text_posting_rule.content.variants.len();
+
+    match text_posting_rule.content.variant_selection {
+        TextPostingRuleVariantSelection::RoundRobin => 1,
+        TextPostingRuleVariantSelection::Random => 0,
+        TextPostingRuleVariantSelection::RandomWithoutRepeat { last_posts } => {
+            last_posts.min(count.saturating_sub(1))
+        }
+    }
+}
@@ -1,14 +1,19 @@
-use crate::{SchedulerClient, StreamAction};
+use crate::{PostCreateClient, StreamAction};
 use aws_lambda_events::event::dynamodb::Event;
 use lambda_runtime::{Error, LambdaEvent};
-use serde_dynamo;
-use telebot_shared::data::{PostingRule, PostingRuleTrait};
+use serde_dynamo::{self, Item};
+use std::time::{SystemTime, UNIX_EPOCH};
+use telebot_shared::{
+    aws::SchedulerClient,
+    data::{PostingRule, PostingRuleTrait},
+    env,
+};
 use tracing::info;
 
 pub async fn handle(event: LambdaEvent<Event>) -> Result<(), Error> {
     let (payload, _context) = event.into_parts();
 
-    let scheduler = SchedulerClient::new().await?;
+    let scheduler = SchedulerClient::new(&env::get_env_var("TARGET_LAMBDA_ARN")?).await?;
 
     if let Some(record) = payload.records.first() {
         let action = StreamAction::from_event_name(&record.event_name);
@@ -16,12 +21,33 @@ pub async fn handle(event: LambdaEvent<Event>) -> Result<(), Error> {
         info!(?record, "Received DynamoDB record");
 
         match action {
-            StreamAction::Insert | StreamAction::Modify => {
+            StreamAction::Insert => {
                 let posting_rule: PostingRule =
                     serde_dynamo::from_item(record.change.new_image.clone())?;
 
                 process_update(&posting_rule, &scheduler).await?;
             }
+            StreamAction::Modify => {
+                if is_only_run_state_changed(&record.change.old_image, &record.change.new_image)? {
+                    info!("Only the run state of the posting rule changed, skipping");
+                    return Ok(());
+                }
+
+                let posting_rule: PostingRule =
+                    serde_dynamo::from_item(record.change.new_image.clone())?;
+
+                process_update(&posting_rule, &scheduler).await?;
+
+                if posting_rule.propagate_edits()
+                    && posting_rule.is_valid()
+                    && is_content_changed(&record.change.old_image, &record.change.new_image)?
+                {
+                    let post_create = PostCreateClient::new().await?;
+                    post_create.edit_latest_post(posting_rule.id()).await?;
+
+                    info!(posting_rule_id = %posting_rule.id(), "Content changed, latest post edit requested");
+                }
+            }
             StreamAction::Remove => {
                 let posting_rule: PostingRule =
                     serde_dynamo::from_item(record.change.old_image.clone())?;
@@ -37,16 +63,82 @@ pub async fn handle(event: LambdaEvent<Event>) -> Result<(), Error> {
     Ok(())
 }
 
+// post_create writes these back on its runs, they do not affect the schedules or the posted content
+
+const RUN_STATE_ATTRIBUTES: [&str; 2] = ["RecentVariantIndices", "LastSkip"];
+
+fn is_only_run_state_changed(old_image: &Item, new_image: &Item) -> Result<bool, Error> {
+    let mut old_posting_rule: serde_json::Value = serde_dynamo::from_item(old_image.clone())?;
+    let mut new_posting_rule: serde_json::Value = serde_dynamo::from_item(new_image.clone())?;
+
+    for posting_rule in [&mut old_posting_rule, &mut new_posting_rule] {
+        if let Some(attributes) = posting_rule.as_object_mut() {
+            for attribute in RUN_STATE_ATTRIBUTES {
+                attributes.remove(attribute);
+            }
+        }
+    }
+
+    Ok(old_posting_rule == new_posting_rule)
+}
+
+fn is_content_changed(old_image: &Item, new_image: &Item) -> Result<bool, Error> {
+    let old_posting_rule: serde_json::Value = serde_dynamo::from_item(old_image.clone())?;
+    let new_posting_rule: serde_json::Value = serde_dynamo::from_item(new_image.clone())?;
+
+    Ok(old_posting_rule.get("Content") != new_posting_rule.get("Content"))
+}
+
 async fn process_update(
     posting_rule: &PostingRule,
     scheduler: &SchedulerClient,
 ) -> Result<(), Error> {
     if !posting_rule.is_valid() {
         scheduler.delete_schedule(posting_rule.id()).await?;
+        scheduler.delete_close_schedule(posting_rule.id()).await?;
+        scheduler.delete_shift_schedule(posting_rule.id()).await?;
         return Ok(());
     }
 
-    scheduler.create_or_update_schedule(posting_rule).await?;
+    // EventBridge rejects an EndDate in the past, a rule that has already ended or completed
+    // needs no posting schedule, but a poll it posted last may still have to be closed
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let has_ended = posting_rule
+        .end_date()
+        .is_some_and(|end_date| end_date <= now);
+
+    if posting_rule.is_completed() || has_ended {
+        scheduler.delete_schedule(posting_rule.id()).await?;
+    } else {
+        scheduler.create_or_update_schedule(posting_rule).await?;
+    }
+
+    let (close_schedule, close_date) = match posting_rule {
+        PostingRule::Poll(poll_posting_rule) => (
+            poll_posting_rule.close_schedule.as_deref(),
+            poll_posting_rule.content.close_date,
+        ),
+        _ => (None, None),
+    };
+
+    // A close date that has passed needs no schedule, it has either fired already or can no longer fire
+
+    match (close_schedule, close_date) {
+        (Some(close_schedule), _) => {
+            scheduler
+                .create_or_update_close_schedule(posting_rule, close_schedule)
+                .await?;
+        }
+        (None, Some(close_date)) if close_date > now => {
+            scheduler
+                .create_or_update_close_date_schedule(posting_rule, close_date)
+                .await?;
+        }
+        _ => {
+            scheduler.delete_close_schedule(posting_rule.id()).await?;
+        }
+    }
 
     Ok(())
 }
@@ -56,5 +148,7 @@ async fn process_remove(
     scheduler: &SchedulerClient,
 ) -> Result<(), Error> {
     scheduler.delete_schedule(posting_rule.id()).await?;
+    scheduler.delete_close_schedule(posting_rule.id()).await?;
+    scheduler.delete_shift_schedule(posting_rule.id()).await?;
     Ok(())
 }
@@ -1,7 +1,7 @@
 pub mod handler;
-pub mod scheduler;
+pub mod post_create;
 pub mod stream;
 
 pub use handler::handle;
-pub use scheduler::SchedulerClient;
+pub use post_create::PostCreateClient;
 pub use stream::StreamAction;
@@ -0,0 +1,49 @@
+use anyhow::{anyhow, Error};
+use aws_sdk_lambda::{primitives::Blob, types::InvocationType, Client};
+use telebot_shared::data::{SchedulerEvent, SchedulerEventAction};
+
+pub struct PostCreateClient {
+    client: Client,
+    target_lambda_arn: String,
+}
+
+impl PostCreateClient {
+    pub async fn new() -> Result<Self, Error> {
+        let target_lambda_arn = std::env::var("TARGET_LAMBDA_ARN")
+            .map_err(|_| anyhow!("TARGET_LAMBDA_ARN environment variable not set"))?;
+
+        let config = aws_config::load_from_env().await;
+        let client = Client::new(&config);
+
+        Ok(Self {
+            client,
+            target_lambda_arn,
+        })
+    }
+
+    pub async fn edit_latest_post(&self, posting_rule_id: &str) -> Result<(), Error> {
+        let payload = SchedulerEvent {
+            posting_rule_id: posting_rule_id.to_string(),
+            action: SchedulerEventAction::EditLatestPost,
+            scheduled_time: None,
+        };
+
+        let payload_bytes = serde_json::to_vec(&payload)?;
+
+        self.client
+            .invoke()
+            .function_name(&self.target_lambda_arn)
+            .invocation_type(InvocationType::Event)
+            .payload(Blob::new(payload_bytes))
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to invoke edit of the latest post of posting rule {posting_rule_id} via {}: {e}",
+                    self.target_lambda_arn
+                )
+            })?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 pub mod dynamodb;
 pub mod errors;
+pub mod scheduler;
 
 pub use dynamodb::DynamoDbClient;
+pub use scheduler::SchedulerClient;
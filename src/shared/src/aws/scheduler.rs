@@ -0,0 +1,404 @@
+use crate::{
+    aws::errors::map_aws_error,
+    data::{
+        PostingRule, PostingRuleTrait, SchedulerEvent, SchedulerEventAction,
+        SCHEDULED_TIME_PLACEHOLDER,
+    },
+    env,
+};
+use anyhow::{anyhow, Error};
+use aws_sdk_scheduler::{
+    primitives::DateTime,
+    types::{
+        ActionAfterCompletion, FlexibleTimeWindow, FlexibleTimeWindowMode, RetryPolicy,
+        ScheduleState, Target,
+    },
+    Client,
+};
+
+// Schedules are managed by schedule_sync, post_create only creates the shift schedule of a rule
+// when a post is moved out of a blackout period. Both target the post_create lambda
+
+pub struct SchedulerClient {
+    client: Client,
+    group_name: String,
+    target_lambda_arn: String,
+    scheduler_role_arn: String,
+    schedule_prefix: String,
+}
+
+impl SchedulerClient {
+    pub async fn new(target_lambda_arn: &str) -> Result<Self, Error> {
+        let scheduler_role_arn = env::get_env_var("SCHEDULER_ROLE_ARN")?;
+        let group_name = env::get_env_var("SCHEDULER_GROUP_NAME")?;
+        let schedule_prefix = env::get_env_var("SCHEDULE_PREFIX")?;
+
+        let config = aws_config::load_from_env().await;
+        let client = Client::new(&config);
+
+        Ok(Self {
+            client,
+            group_name,
+            target_lambda_arn: target_lambda_arn.to_string(),
+            scheduler_role_arn,
+            schedule_prefix,
+        })
+    }
+
+    fn schedule_name(&self, posting_rule_id: &str) -> String {
+        format!("{}{}", self.schedule_prefix, posting_rule_id)
+    }
+
+    // Rules with a list of schedules get one EventBridge schedule per entry, numbered from 1.
+    // The prefix must not be a prefix of the other suffixes such as -shift, they are listed by it
+
+    fn schedule_entry_name(&self, posting_rule_id: &str, index: usize) -> String {
+        format!(
+            "{}{}",
+            self.schedule_entry_prefix(posting_rule_id),
+            index + 1
+        )
+    }
+
+    fn schedule_entry_prefix(&self, posting_rule_id: &str) -> String {
+        format!("{}{}-entry-", self.schedule_prefix, posting_rule_id)
+    }
+
+    fn close_schedule_name(&self, posting_rule_id: &str) -> String {
+        format!("{}{}-close", self.schedule_prefix, posting_rule_id)
+    }
+
+    fn shift_schedule_name(&self, posting_rule_id: &str) -> String {
+        format!("{}{}-shift", self.schedule_prefix, posting_rule_id)
+    }
+
+    pub async fn create_or_update_schedule(
+        &self,
+        posting_rule: &PostingRule,
+    ) -> Result<(), anyhow::Error> {
+        let payload = SchedulerEvent {
+            posting_rule_id: posting_rule.id().to_string(),
+            action: SchedulerEventAction::Post,
+            scheduled_time: Some(SCHEDULED_TIME_PLACEHOLDER.to_string()),
+        };
+
+        if !posting_rule.schedules().is_empty() {
+            for (i, entry) in posting_rule.schedules().iter().enumerate() {
+                let timezone = entry.timezone.as_deref().unwrap_or(posting_rule.timezone());
+
+                self.upsert_schedule(
+                    &self.schedule_entry_name(posting_rule.id(), i),
+                    &format!("cron({})", entry.schedule.trim()),
+                    ActionAfterCompletion::None,
+                    timezone,
+                    posting_rule,
+                    &payload,
+                )
+                .await?;
+            }
+
+            self.delete_schedule_by_name(&self.schedule_name(posting_rule.id()))
+                .await?;
+
+            return self
+                .delete_schedule_entries(posting_rule.id(), posting_rule.schedules().len())
+                .await;
+        }
+
+        // One-shot rules fire once at a local date and time and the schedule removes itself afterwards
+
+        let (schedule_expression, action_after_completion) = match posting_rule.run_at() {
+            Some(run_at) => (
+                format!("at({})", run_at.trim()),
+                ActionAfterCompletion::Delete,
+            ),
+            None => (
+                format!("cron({})", posting_rule.schedule().trim()),
+                ActionAfterCompletion::None,
+            ),
+        };
+
+        self.upsert_schedule(
+            &self.schedule_name(posting_rule.id()),
+            &schedule_expression,
+            action_after_completion,
+            posting_rule.timezone(),
+            posting_rule,
+            &payload,
+        )
+        .await?;
+
+        self.delete_schedule_entries(posting_rule.id(), 0).await
+    }
+
+    pub async fn create_or_update_close_schedule(
+        &self,
+        posting_rule: &PostingRule,
+        close_schedule: &str,
+    ) -> Result<(), anyhow::Error> {
+        let payload = SchedulerEvent {
+            posting_rule_id: posting_rule.id().to_string(),
+            action: SchedulerEventAction::ClosePoll,
+            scheduled_time: Some(SCHEDULED_TIME_PLACEHOLDER.to_string()),
+        };
+
+        self.upsert_schedule(
+            &self.close_schedule_name(posting_rule.id()),
+            &format!("cron({})", close_schedule.trim()),
+            ActionAfterCompletion::None,
+            posting_rule.timezone(),
+            posting_rule,
+            &payload,
+        )
+        .await
+    }
+
+    // A close date is a UTC timestamp, the schedule fires once at it and removes itself afterwards
+
+    pub async fn create_or_update_close_date_schedule(
+        &self,
+        posting_rule: &PostingRule,
+        close_date: i64,
+    ) -> Result<(), anyhow::Error> {
+        let payload = SchedulerEvent {
+            posting_rule_id: posting_rule.id().to_string(),
+            action: SchedulerEventAction::ClosePoll,
+            scheduled_time: Some(SCHEDULED_TIME_PLACEHOLDER.to_string()),
+        };
+
+        let close_date = chrono::DateTime::from_timestamp(close_date, 0)
+            .ok_or_else(|| anyhow!("Invalid poll close date: {}", close_date))?;
+
+        self.upsert_schedule(
+            &self.close_schedule_name(posting_rule.id()),
+            &format!("at({})", close_date.format("%Y-%m-%dT%H:%M:%S")),
+            ActionAfterCompletion::Delete,
+            "UTC",
+            posting_rule,
+            &payload,
+        )
+        .await
+    }
+
+    // The shift schedule fires once at a local date and time and removes itself afterwards
+
+    pub async fn create_or_update_shift_schedule(
+        &self,
+        posting_rule: &PostingRule,
+        run_at: &str,
+    ) -> Result<(), anyhow::Error> {
+        let payload = SchedulerEvent {
+            posting_rule_id: posting_rule.id().to_string(),
+            action: SchedulerEventAction::Post,
+            scheduled_time: Some(SCHEDULED_TIME_PLACEHOLDER.to_string()),
+        };
+
+        self.upsert_schedule(
+            &self.shift_schedule_name(posting_rule.id()),
+            &format!("at({})", run_at.trim()),
+            ActionAfterCompletion::Delete,
+            posting_rule.timezone(),
+            posting_rule,
+            &payload,
+        )
+        .await
+    }
+
+    pub async fn delete_schedule(&self, posting_rule_id: &str) -> Result<(), Error> {
+        self.delete_schedule_by_name(&self.schedule_name(posting_rule_id))
+            .await?;
+
+        self.delete_schedule_entries(posting_rule_id, 0).await
+    }
+
+    pub async fn delete_close_schedule(&self, posting_rule_id: &str) -> Result<(), Error> {
+        self.delete_schedule_by_name(&self.close_schedule_name(posting_rule_id))
+            .await
+    }
+
+    pub async fn delete_shift_schedule(&self, posting_rule_id: &str) -> Result<(), Error> {
+        self.delete_schedule_by_name(&self.shift_schedule_name(posting_rule_id))
+            .await
+    }
+
+    async fn upsert_schedule(
+        &self,
+        schedule_name: &str,
+        schedule_expression: &str,
+        action_after_completion: ActionAfterCompletion,
+        timezone: &str,
+        posting_rule: &PostingRule,
+        payload: &SchedulerEvent,
+    ) -> Result<(), anyhow::Error> {
+        let payload_json = serde_json::to_string(payload)
+            .map_err(|_| anyhow!("Failed to serialize scheduler payload"))?;
+
+        let retry_policy = RetryPolicy::builder()
+            .maximum_event_age_in_seconds(60)
+            .maximum_retry_attempts(0)
+            .build();
+
+        let target = Target::builder()
+            .arn(&self.target_lambda_arn)
+            .role_arn(&self.scheduler_role_arn)
+            .retry_policy(retry_policy)
+            .input(payload_json)
+            .build()
+            .map_err(|e| anyhow!("Failed to build target: {e}"))?;
+
+        let flexible_time_window = FlexibleTimeWindow::builder()
+            .mode(FlexibleTimeWindowMode::Off)
+            .build()
+            .map_err(|_| anyhow!("Failed to build flexible time window"))?;
+
+        // Start and end dates bound the posting only, a poll posted before the end date must still be closed
+
+        let (start_date, end_date) = match payload.action {
+            SchedulerEventAction::Post => (
+                posting_rule.start_date().map(DateTime::from_secs),
+                posting_rule.end_date().map(DateTime::from_secs),
+            ),
+            _ => (None, None),
+        };
+
+        // Close schedules stay enabled for inactive and completed rules so that the poll they posted last
+        // is still closed, post_create skips polls that are already closed
+
+        let state = if posting_rule.is_active() || payload.action == SchedulerEventAction::ClosePoll
+        {
+            ScheduleState::Enabled
+        } else {
+            ScheduleState::Disabled
+        };
+
+        let schedule_exists = self.schedule_exists(schedule_name).await?;
+
+        if schedule_exists {
+            self.client
+                .update_schedule()
+                .group_name(&self.group_name)
+                .name(schedule_name)
+                .state(state)
+                .schedule_expression(schedule_expression)
+                .schedule_expression_timezone(timezone.to_string())
+                .action_after_completion(action_after_completion)
+                .set_start_date(start_date)
+                .set_end_date(end_date)
+                .target(target)
+                .flexible_time_window(flexible_time_window)
+                .send()
+                .await
+                .map_err(map_aws_error)?;
+        } else {
+            self.client
+                .create_schedule()
+                .group_name(&self.group_name)
+                .name(schedule_name)
+                .state(state)
+                .schedule_expression(schedule_expression)
+                .schedule_expression_timezone(timezone.to_string())
+                .action_after_completion(action_after_completion)
+                .set_start_date(start_date)
+                .set_end_date(end_date)
+                .target(target)
+                .flexible_time_window(flexible_time_window)
+                .send()
+                .await
+                .map_err(map_aws_error)?;
+        }
+
+        Ok(())
+    }
+
+    // Deletes the entry schedules starting from the given count, entries below it are still in use
+
+    async fn delete_schedule_entries(
+        &self,
+        posting_rule_id: &str,
+        keep: usize,
+    ) -> Result<(), Error> {
+        let entry_names_to_keep = (0..keep)
+            .map(|i| self.schedule_entry_name(posting_rule_id, i))
+            .collect::<Vec<_>>();
+
+        for name in self
+            .list_schedule_names(&self.schedule_entry_prefix(posting_rule_id))
+            .await?
+        {
+            if !entry_names_to_keep.contains(&name) {
+                self.delete_schedule_by_name(&name).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn list_schedule_names(&self, name_prefix: &str) -> Result<Vec<String>, Error> {
+        let mut names = vec![];
+        let mut next_token = None;
+
+        loop {
+            let result = self
+                .client
+                .list_schedules()
+                .group_name(&self.group_name)
+                .name_prefix(name_prefix)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(map_aws_error)?;
+
+            names.extend(
+                result
+                    .schedules()
+                    .iter()
+                    .filter_map(|schedule| schedule.name())
+                    .map(|name| name.to_string()),
+            );
+
+            match result.next_token() {
+                Some(token) => next_token = Some(token.to_string()),
+                None => break,
+            }
+        }
+
+        Ok(names)
+    }
+
+    async fn delete_schedule_by_name(&self, schedule_name: &str) -> Result<(), Error> {
+        if !self.schedule_exists(schedule_name).await? {
+            return Ok(());
+        }
+
+        self.client
+            .delete_schedule()
+            .group_name(&self.group_name)
+            .name(schedule_name)
+            .send()
+            .await
+            .map_err(map_aws_error)?;
+
+        Ok(())
+    }
+
+    async fn schedule_exists(&self, schedule_name: &str) -> Result<bool, Error> {
+        match self
+            .client
+            .get_schedule()
+            .group_name(&self.group_name)
+            .name(schedule_name)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                let service_error = e.into_service_error();
+                if service_error.is_resource_not_found_exception() {
+                    Ok(false)
+                } else {
+                    Err(anyhow!(service_error.to_string()))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,805 @@
+use crate::cron::{CronExpression, CronFieldKind, DayOfMonth, DayOfWeek};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronLanguage {
+    Russian,
+    English,
+}
+
+// Lists with more hour and minute combinations than this are described by their pattern
+
+const MAX_LISTED_TIMES: usize = 6;
+
+const WEEKDAYS_EN: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const WEEKDAYS_RU_ACCUSATIVE: [&str; 7] = [
+    "воскресенье",
+    "понедельник",
+    "вторник",
+    "среду",
+    "четверг",
+    "пятницу",
+    "субботу",
+];
+
+const WEEKDAYS_RU_GENITIVE: [&str; 7] = [
+    "воскресенья",
+    "понедельника",
+    "вторника",
+    "среды",
+    "четверга",
+    "пятницы",
+    "субботы",
+];
+
+const WEEKDAYS_RU_DATIVE_PLURAL: [&str; 7] = [
+    "воскресеньям",
+    "понедельникам",
+    "вторникам",
+    "средам",
+    "четвергам",
+    "пятницам",
+    "субботам",
+];
+
+const WEEKDAYS_RU_GENDER: [Gender; 7] = [
+    Gender::Neuter,
+    Gender::Masculine,
+    Gender::Masculine,
+    Gender::Feminine,
+    Gender::Masculine,
+    Gender::Feminine,
+    Gender::Feminine,
+];
+
+const MONTHS_EN: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const MONTHS_RU_NOMINATIVE: [&str; 12] = [
+    "январь",
+    "февраль",
+    "март",
+    "апрель",
+    "май",
+    "июнь",
+    "июль",
+    "август",
+    "сентябрь",
+    "октябрь",
+    "ноябрь",
+    "декабрь",
+];
+
+const MONTHS_RU_GENITIVE: [&str; 12] = [
+    "января",
+    "февраля",
+    "марта",
+    "апреля",
+    "мая",
+    "июня",
+    "июля",
+    "августа",
+    "сентября",
+    "октября",
+    "ноября",
+    "декабря",
+];
+
+const MONTHS_RU_PREPOSITIONAL: [&str; 12] = [
+    "январе",
+    "феврале",
+    "марте",
+    "апреле",
+    "мае",
+    "июне",
+    "июле",
+    "августе",
+    "сентябре",
+    "октябре",
+    "ноябре",
+    "декабре",
+];
+
+const ORDINALS_EN: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+const ORDINALS_RU: [[&str; 3]; 5] = [
+    ["первый", "первую", "первое"],
+    ["второй", "вторую", "второе"],
+    ["третий", "третью", "третье"],
+    ["четвёртый", "четвёртую", "четвёртое"],
+    ["пятый", "пятую", "пятое"],
+];
+
+#[derive(Debug, Clone, Copy)]
+enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+// Field values are expanded into sets by the parser, the pattern is restored from the set
+
+#[derive(Debug)]
+enum Values {
+    Every,
+    Step { start: u32, step: u32 },
+    List(Vec<ValueItem>),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ValueItem {
+    Single(u32),
+    Range(u32, u32),
+}
+
+impl CronExpression {
+    pub fn describe(&self, language: CronLanguage) -> String {
+        let mut has_months = false;
+
+        let day = match (&self.day_of_month, &self.day_of_week) {
+            (DayOfMonth::Days(days), DayOfWeek::Any) if self.months.len() == 1 => {
+                match to_values(days, CronFieldKind::DayOfMonth) {
+                    Values::List(items) => {
+                        let month = *self.months.first().unwrap_or(&1);
+                        has_months = true;
+
+                        describe_yearly_date(&items, month, language)
+                    }
+                    _ => describe_day_of_month(&self.day_of_month, &self.months, language),
+                }
+            }
+            (day_of_month, DayOfWeek::Any) => {
+                describe_day_of_month(day_of_month, &self.months, language)
+            }
+            (_, day_of_week) => describe_day_of_week(day_of_week, language),
+        };
+
+        let (time, is_time_listed) = describe_time(&self.minutes, &self.hours, language);
+
+        // "Every day every 15 minutes" reads as a repetition, the day is only named before exact times
+
+        let mut parts = vec![];
+
+        if day != every_day(language) || is_time_listed {
+            parts.push(day);
+        }
+
+        if !has_months {
+            if let Some(months) = describe_months(&self.months, language) {
+                parts.push(months);
+            }
+        }
+
+        parts.push(time);
+
+        if let Some(years) = describe_years(&self.years, language) {
+            parts.push(years);
+        }
+
+        parts.join(" ")
+    }
+}
+
+fn describe_day_of_month(
+    day_of_month: &DayOfMonth,
+    months: &BTreeSet<u32>,
+    language: CronLanguage,
+) -> String {
+    let every_month = is_every(months, CronFieldKind::Month);
+
+    match (day_of_month, language) {
+        (DayOfMonth::Any, _) => every_day(language),
+        (DayOfMonth::Days(days), _) => match (to_values(days, CronFieldKind::DayOfMonth), language)
+        {
+            (Values::Every, _) => every_day(language),
+            (Values::Step { start, step }, CronLanguage::Russian) => format!(
+                "каждые {} {}, начиная с {} числа",
+                step,
+                plural_ru(step, "день", "дня", "дней"),
+                start
+            ),
+            (Values::Step { start, step }, CronLanguage::English) => {
+                format!("every {} days starting on day {}", step, start)
+            }
+            (Values::List(items), CronLanguage::Russian) if every_month => {
+                format!("{} числа каждого месяца", join_items(&items, language))
+            }
+            (Values::List(items), CronLanguage::Russian) => {
+                format!("{} числа", join_items(&items, language))
+            }
+            (Values::List(items), CronLanguage::English) if every_month => {
+                format!("on day {} of every month", join_items(&items, language))
+            }
+            (Values::List(items), CronLanguage::English) => {
+                format!("on day {} of the month", join_items(&items, language))
+            }
+        },
+        (DayOfMonth::Last, CronLanguage::Russian) => "в последний день месяца".to_string(),
+        (DayOfMonth::Last, CronLanguage::English) => "on the last day of the month".to_string(),
+        (DayOfMonth::LastWeekday, CronLanguage::Russian) => {
+            "в последний рабочий день месяца".to_string()
+        }
+        (DayOfMonth::LastWeekday, CronLanguage::English) => {
+            "on the last weekday of the month".to_string()
+        }
+        (DayOfMonth::NearestWeekday(day), CronLanguage::Russian) => {
+            format!("в ближайший к {} числу рабочий день", day)
+        }
+        (DayOfMonth::NearestWeekday(day), CronLanguage::English) => {
+            format!("on the weekday nearest to day {}", day)
+        }
+    }
+}
+
+fn describe_yearly_date(items: &[ValueItem], month: u32, language: CronLanguage) -> String {
+    let month_index = (month - 1) as usize;
+
+    match language {
+        CronLanguage::Russian => format!(
+            "каждый год {} {}",
+            join_items(items, language),
+            MONTHS_RU_GENITIVE[month_index]
+        ),
+        CronLanguage::English => format!(
+            "every year on {} {}",
+            MONTHS_EN[month_index],
+            join_items(items, language)
+        ),
+    }
+}
+
+fn describe_day_of_week(day_of_week: &DayOfWeek, language: CronLanguage) -> String {
+    match day_of_week {
+        DayOfWeek::Any => every_day(language),
+        DayOfWeek::Days(days) => describe_weekdays(days, language),
+        DayOfWeek::Last(day) => {
+            let index = (day - 1) as usize;
+
+            match language {
+                CronLanguage::Russian => format!(
+                    "в {} {} месяца",
+                    gendered_ru(
+                        WEEKDAYS_RU_GENDER[index],
+                        ["последний", "последнюю", "последнее"]
+                    ),
+                    WEEKDAYS_RU_ACCUSATIVE[index]
+                ),
+                CronLanguage::English => {
+                    format!("on the last {} of the month", WEEKDAYS_EN[index])
+                }
+            }
+        }
+        DayOfWeek::Nth(day, nth) => {
+            let index = (day - 1) as usize;
+            let nth_index = (nth - 1) as usize;
+
+            match language {
+                CronLanguage::Russian => {
+                    let ordinal = gendered_ru(WEEKDAYS_RU_GENDER[index], ORDINALS_RU[nth_index]);
+                    let preposition = if ordinal.starts_with("втор") {
+                        "во"
+                    } else {
+                        "в"
+                    };
+
+                    format!(
+                        "{} {} {} месяца",
+                        preposition, ordinal, WEEKDAYS_RU_ACCUSATIVE[index]
+                    )
+                }
+                CronLanguage::English => format!(
+                    "on the {} {} of the month",
+                    ORDINALS_EN[nth_index], WEEKDAYS_EN[index]
+                ),
+            }
+        }
+    }
+}
+
+fn describe_weekdays(days: &BTreeSet<u32>, language: CronLanguage) -> String {
+    let indexes = days
+        .iter()
+        .map(|day| (day - 1) as usize)
+        .collect::<Vec<_>>();
+
+    if days.len() == 7 {
+        return every_day(language);
+    }
+
+    if *days == BTreeSet::from([2, 3, 4, 5, 6]) {
+        return match language {
+            CronLanguage::Russian => "по будням".to_string(),
+            CronLanguage::English => "on weekdays".to_string(),
+        };
+    }
+
+    if *days == BTreeSet::from([1, 7]) {
+        return match language {
+            CronLanguage::Russian => "по выходным".to_string(),
+            CronLanguage::English => "on weekends".to_string(),
+        };
+    }
+
+    if let [index] = indexes[..] {
+        return match language {
+            CronLanguage::Russian => format!(
+                "{} {}",
+                gendered_ru(WEEKDAYS_RU_GENDER[index], ["каждый", "каждую", "каждое"]),
+                WEEKDAYS_RU_ACCUSATIVE[index]
+            ),
+            CronLanguage::English => format!("every {}", WEEKDAYS_EN[index]),
+        };
+    }
+
+    if let [ValueItem::Range(first, last)] = to_items(days)[..] {
+        let (first, last) = ((first - 1) as usize, (last - 1) as usize);
+
+        if last - first >= 2 {
+            return match language {
+                CronLanguage::Russian => format!(
+                    "{} {} по {}",
+                    if matches!(first, 2 | 3) { "со" } else { "с" },
+                    WEEKDAYS_RU_GENITIVE[first],
+                    WEEKDAYS_RU_ACCUSATIVE[last]
+                ),
+                CronLanguage::English => {
+                    format!("from {} to {}", WEEKDAYS_EN[first], WEEKDAYS_EN[last])
+                }
+            };
+        }
+    }
+
+    match language {
+        CronLanguage::Russian => {
+            let names = indexes
+                .iter()
+                .map(|index| WEEKDAYS_RU_DATIVE_PLURAL[*index].to_string())
+                .collect::<Vec<_>>();
+
+            format!("по {}", join_words(&names, language))
+        }
+        CronLanguage::English => {
+            let names = indexes
+                .iter()
+                .map(|index| format!("{}s", WEEKDAYS_EN[*index]))
+                .collect::<Vec<_>>();
+
+            format!("on {}", join_words(&names, language))
+        }
+    }
+}
+
+fn describe_months(months: &BTreeSet<u32>, language: CronLanguage) -> Option<String> {
+    if is_every(months, CronFieldKind::Month) {
+        return None;
+    }
+
+    if let [ValueItem::Range(first, last)] = to_items(months)[..] {
+        if last - first >= 2 {
+            let (first, last) = ((first - 1) as usize, (last - 1) as usize);
+
+            return Some(match language {
+                CronLanguage::Russian => format!(
+                    "с {} по {}",
+                    MONTHS_RU_GENITIVE[first], MONTHS_RU_NOMINATIVE[last]
+                ),
+                CronLanguage::English => {
+                    format!("from {} to {}", MONTHS_EN[first], MONTHS_EN[last])
+                }
+            });
+        }
+    }
+
+    let names = months
+        .iter()
+        .map(|month| {
+            let index = (month - 1) as usize;
+
+            match language {
+                CronLanguage::Russian => MONTHS_RU_PREPOSITIONAL[index].to_string(),
+                CronLanguage::English => MONTHS_EN[index].to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Some(match language {
+        CronLanguage::Russian => format!("в {}", join_words(&names, language)),
+        CronLanguage::English => format!("in {}", join_words(&names, language)),
+    })
+}
+
+// Returns the description and whether it lists exact times of day
+
+fn describe_time(
+    minutes: &BTreeSet<u32>,
+    hours: &BTreeSet<u32>,
+    language: CronLanguage,
+) -> (String, bool) {
+    let minute_values = to_values(minutes, CronFieldKind::Minutes);
+    let hour_values = to_values(hours, CronFieldKind::Hours);
+
+    if let (Values::List(_), Values::List(_)) = (&minute_values, &hour_values) {
+        if minutes.len() * hours.len() <= MAX_LISTED_TIMES {
+            let times = hours
+                .iter()
+                .flat_map(|hour| {
+                    minutes
+                        .iter()
+                        .map(move |minute| format!("{:02}:{:02}", hour, minute))
+                })
+                .collect::<Vec<_>>();
+
+            let description = match language {
+                CronLanguage::Russian => format!("в {}", join_words(&times, language)),
+                CronLanguage::English => format!("at {}", join_words(&times, language)),
+            };
+
+            return (description, true);
+        }
+    }
+
+    let mut parts = vec![];
+
+    match (&minute_values, language) {
+        (Values::Every, CronLanguage::Russian) => parts.push("каждую минуту".to_string()),
+        (Values::Every, CronLanguage::English) => parts.push("every minute".to_string()),
+        (Values::Step { start, step }, CronLanguage::Russian) => {
+            let mut part = format!(
+                "каждые {} {}",
+                step,
+                plural_ru(*step, "минуту", "минуты", "минут")
+            );
+
+            if *start > 0 {
+                part.push_str(&format!(", начиная с {}-й минуты", start));
+            }
+
+            parts.push(part);
+        }
+        (Values::Step { start, step }, CronLanguage::English) => {
+            let mut part = format!("every {} minutes", step);
+
+            if *start > 0 {
+                part.push_str(&format!(" starting at minute {}", start));
+            }
+
+            parts.push(part);
+        }
+        (Values::List(_), _) if *minutes == BTreeSet::from([0]) => {
+            if !matches!(hour_values, Values::Step { .. }) {
+                parts.push(match language {
+                    CronLanguage::Russian => "каждый час".to_string(),
+                    CronLanguage::English => "every hour".to_string(),
+                });
+            }
+        }
+        (Values::List(items), CronLanguage::Russian) => {
+            let last = minutes.last().copied().unwrap_or(0);
+
+            parts.push(format!(
+                "в {} {} после начала часа",
+                join_items(items, language),
+                plural_ru(last, "минуту", "минуты", "минут")
+            ));
+        }
+        (Values::List(items), CronLanguage::English) => parts.push(format!(
+            "at {} minutes past the hour",
+            join_items(items, language)
+        )),
+    }
+
+    match (&hour_values, language) {
+        (Values::Every, _) => {}
+        (Values::Step { start, step }, CronLanguage::Russian) => {
+            let mut part = format!(
+                "каждые {} {}",
+                step,
+                plural_ru(*step, "час", "часа", "часов")
+            );
+
+            if *start > 0 {
+                part.push_str(&format!(", начиная с {:02}:00", start));
+            }
+
+            parts.push(part);
+        }
+        (Values::Step { start, step }, CronLanguage::English) => {
+            let mut part = format!("every {} hours", step);
+
+            if *start > 0 {
+                part.push_str(&format!(" starting at {:02}:00", start));
+            }
+
+            parts.push(part);
+        }
+        (Values::List(items), _) => {
+            if let [ValueItem::Range(first, last)] = items[..] {
+                parts.push(match language {
+                    CronLanguage::Russian => format!("с {:02}:00 до {:02}:59", first, last),
+                    CronLanguage::English => format!("from {:02}:00 to {:02}:59", first, last),
+                });
+            } else {
+                let intervals = items
+                    .iter()
+                    .map(|item| match item {
+                        ValueItem::Single(hour) => format!("{:02}:00–{:02}:59", hour, hour),
+                        ValueItem::Range(first, last) => {
+                            format!("{:02}:00–{:02}:59", first, last)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                parts.push(match language {
+                    CronLanguage::Russian => {
+                        format!("в интервалы {}", join_words(&intervals, language))
+                    }
+                    CronLanguage::English => {
+                        format!("during {}", join_words(&intervals, language))
+                    }
+                });
+            }
+        }
+    }
+
+    (parts.join(" "), false)
+}
+
+fn describe_years(years: &BTreeSet<u32>, language: CronLanguage) -> Option<String> {
+    if is_every(years, CronFieldKind::Year) {
+        return None;
+    }
+
+    let items = to_items(years);
+
+    Some(match language {
+        CronLanguage::Russian if years.len() == 1 => {
+            format!("в {} году", join_items(&items, language))
+        }
+        CronLanguage::Russian => format!("в {} годах", join_items(&items, language)),
+        CronLanguage::English => format!("in {}", join_items(&items, language)),
+    })
+}
+
+fn every_day(language: CronLanguage) -> String {
+    match language {
+        CronLanguage::Russian => "каждый день".to_string(),
+        CronLanguage::English => "every day".to_string(),
+    }
+}
+
+fn is_every(values: &BTreeSet<u32>, kind: CronFieldKind) -> bool {
+    let (min, max) = kind.range();
+
+    values.len() == (max - min + 1) as usize
+}
+
+// A step is recognised when the values are evenly spaced and run up to the end of the range
+
+fn to_values(values: &BTreeSet<u32>, kind: CronFieldKind) -> Values {
+    let (min, max) = kind.range();
+
+    if is_every(values, kind) {
+        return Values::Every;
+    }
+
+    let ordered = values.iter().copied().collect::<Vec<_>>();
+
+    if let [first, second, ..] = ordered[..] {
+        let step = second - first;
+        let last = ordered[ordered.len() - 1];
+
+        let is_evenly_spaced = ordered.windows(2).all(|pair| pair[1] - pair[0] == step);
+
+        if step > 1 && is_evenly_spaced && first - min < step && last + step > max {
+            return Values::Step { start: first, step };
+        }
+    }
+
+    Values::List(to_items(values))
+}
+
+// Consecutive values are merged into ranges when there are at least three of them
+
+fn to_items(values: &BTreeSet<u32>) -> Vec<ValueItem> {
+    let mut runs: Vec<(u32, u32)> = vec![];
+
+    for value in values {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == *value => *end = *value,
+            _ => runs.push((*value, *value)),
+        }
+    }
+
+    runs.into_iter()
+        .flat_map(|(start, end)| match end - start {
+            0 => vec![ValueItem::Single(start)],
+            1 => vec![ValueItem::Single(start), ValueItem::Single(end)],
+            _ => vec![ValueItem::Range(start, end)],
+        })
+        .collect()
+}
+
+fn join_items(items: &[ValueItem], language: CronLanguage) -> String {
+    let words = items
+        .iter()
+        .map(|item| match item {
+            ValueItem::Single(value) => value.to_string(),
+            ValueItem::Range(start, end) => format!("{}–{}", start, end),
+        })
+        .collect::<Vec<_>>();
+
+    join_words(&words, language)
+}
+
+fn join_words(words: &[String], language: CronLanguage) -> String {
+    let conjunction = match language {
+        CronLanguage::Russian => " и ",
+        CronLanguage::English => " and ",
+    };
+
+    match words {
+        [] => String::new(),
+        [word] => word.clone(),
+        [rest @ .., last] => format!("{}{}{}", rest.join(", "), conjunction, last),
+    }
+}
+
+fn gendered_ru(gender: Gender, forms: [&'static str; 3]) -> &'static str {
+    match gender {
+        Gender::Masculine => forms[0],
+        Gender::Feminine => forms[1],
+        Gender::Neuter => forms[2],
+    }
+}
+
+fn plural_ru<'a>(number: u32, one: &'a str, few: &'a str, many: &'a str) -> &'a str {
+    match (number % 10, number % 100) {
+        (1, n) if n != 11 => one,
+        (2..=4, n) if !(12..=14).contains(&n) => few,
+        _ => many,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(expression: &str, language: CronLanguage) -> String {
+        CronExpression::parse(expression)
+            .unwrap()
+            .describe(language)
+    }
+
+    #[test]
+    fn describes_daily_times() {
+        assert_eq!(
+            describe("0 9 * * ? *", CronLanguage::Russian),
+            "каждый день в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 * * ? *", CronLanguage::English),
+            "every day at 09:00"
+        );
+        assert_eq!(
+            describe("30 9,18 * * ? *", CronLanguage::Russian),
+            "каждый день в 09:30 и 18:30"
+        );
+    }
+
+    #[test]
+    fn describes_repetitions_without_the_day() {
+        assert_eq!(
+            describe("0/15 * * * ? *", CronLanguage::Russian),
+            "каждые 15 минут"
+        );
+        assert_eq!(
+            describe("0/15 * * * ? *", CronLanguage::English),
+            "every 15 minutes"
+        );
+        assert_eq!(
+            describe("0 0/2 * * ? *", CronLanguage::Russian),
+            "каждые 2 часа"
+        );
+        assert_eq!(
+            describe("0 9-18 * * ? *", CronLanguage::English),
+            "every hour from 09:00 to 18:59"
+        );
+    }
+
+    #[test]
+    fn describes_weekdays() {
+        assert_eq!(
+            describe("0 9 ? * MON-FRI *", CronLanguage::Russian),
+            "по будням в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * SUN,SAT *", CronLanguage::English),
+            "on weekends at 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * WED *", CronLanguage::Russian),
+            "каждую среду в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * TUE-THU *", CronLanguage::Russian),
+            "со вторника по четверг в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * MON,FRI *", CronLanguage::English),
+            "on Mondays and Fridays at 09:00"
+        );
+    }
+
+    #[test]
+    fn describes_special_days() {
+        assert_eq!(
+            describe("0 9 L * ? *", CronLanguage::Russian),
+            "в последний день месяца в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 LW * ? *", CronLanguage::English),
+            "on the last weekday of the month at 09:00"
+        );
+        assert_eq!(
+            describe("0 9 15W * ? *", CronLanguage::Russian),
+            "в ближайший к 15 числу рабочий день в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * 6L *", CronLanguage::Russian),
+            "в последнюю пятницу месяца в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * 3#2 *", CronLanguage::Russian),
+            "во второй вторник месяца в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 ? * 3#2 *", CronLanguage::English),
+            "on the second Tuesday of the month at 09:00"
+        );
+    }
+
+    #[test]
+    fn describes_months_and_years() {
+        assert_eq!(
+            describe("0 9 1 * ? *", CronLanguage::Russian),
+            "1 числа каждого месяца в 09:00"
+        );
+        assert_eq!(
+            describe("0 12 25 12 ? *", CronLanguage::Russian),
+            "каждый год 25 декабря в 12:00"
+        );
+        assert_eq!(
+            describe("0 12 25 12 ? *", CronLanguage::English),
+            "every year on December 25 at 12:00"
+        );
+        assert_eq!(
+            describe("0 9 ? 6-8 MON *", CronLanguage::Russian),
+            "каждый понедельник с июня по август в 09:00"
+        );
+        assert_eq!(
+            describe("0 9 * * ? 2026", CronLanguage::Russian),
+            "каждый день в 09:00 в 2026 году"
+        );
+    }
+}
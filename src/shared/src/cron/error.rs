@@ -0,0 +1,77 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronFieldKind {
+    Minutes,
+    Hours,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+    Year,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronError {
+    pub field: Option<CronFieldKind>,
+    pub message: String,
+}
+
+impl CronFieldKind {
+    pub const ALL: [CronFieldKind; 6] = [
+        CronFieldKind::Minutes,
+        CronFieldKind::Hours,
+        CronFieldKind::DayOfMonth,
+        CronFieldKind::Month,
+        CronFieldKind::DayOfWeek,
+        CronFieldKind::Year,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CronFieldKind::Minutes => "Minutes",
+            CronFieldKind::Hours => "Hours",
+            CronFieldKind::DayOfMonth => "Day-of-month",
+            CronFieldKind::Month => "Month",
+            CronFieldKind::DayOfWeek => "Day-of-week",
+            CronFieldKind::Year => "Year",
+        }
+    }
+
+    pub fn range(&self) -> (u32, u32) {
+        match self {
+            CronFieldKind::Minutes => (0, 59),
+            CronFieldKind::Hours => (0, 23),
+            CronFieldKind::DayOfMonth => (1, 31),
+            CronFieldKind::Month => (1, 12),
+            CronFieldKind::DayOfWeek => (1, 7),
+            CronFieldKind::Year => (1970, 2199),
+        }
+    }
+}
+
+impl CronError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            field: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn field(field: CronFieldKind, message: impl Into<String>) -> Self {
+        Self {
+            field: Some(field),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "{}: {}", field.name(), self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
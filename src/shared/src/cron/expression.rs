@@ -0,0 +1,425 @@
+use crate::cron::{field, CronError, CronFieldKind};
+use chrono::{DateTime, Datelike, Days, LocalResult, Months, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeSet;
+
+// EventBridge cron expressions: Minutes Hours Day-of-month Month Day-of-week Year
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpression {
+    pub minutes: BTreeSet<u32>,
+    pub hours: BTreeSet<u32>,
+    pub day_of_month: DayOfMonth,
+    pub months: BTreeSet<u32>,
+    pub day_of_week: DayOfWeek,
+    pub years: BTreeSet<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOfMonth {
+    Any,
+    Days(BTreeSet<u32>),
+    Last,
+    LastWeekday,
+    NearestWeekday(u32),
+}
+
+// Days of week are numbered from 1 (Sunday) to 7 (Saturday)
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOfWeek {
+    Any,
+    Days(BTreeSet<u32>),
+    Last(u32),
+    Nth(u32, u32),
+}
+
+impl CronExpression {
+    // Collects the errors of every field instead of stopping at the first one
+
+    pub fn parse(expression: &str) -> Result<Self, Vec<CronError>> {
+        let expression = expression.trim();
+
+        let expression = expression
+            .strip_prefix("cron(")
+            .and_then(|expression| expression.strip_suffix(')'))
+            .unwrap_or(expression);
+
+        let parts = expression.split_whitespace().collect::<Vec<_>>();
+
+        if parts.len() != 6 {
+            return Err(vec![CronError::new(format!(
+                "must have 6 fields, found {}",
+                parts.len()
+            ))]);
+        }
+
+        let mut errors = vec![];
+
+        let minutes = field::parse_values(CronFieldKind::Minutes, parts[0]);
+        let hours = field::parse_values(CronFieldKind::Hours, parts[1]);
+        let day_of_month = field::parse_day_of_month(parts[2]);
+        let months = field::parse_values(CronFieldKind::Month, parts[3]);
+        let day_of_week = field::parse_day_of_week(parts[4]);
+        let years = field::parse_values(CronFieldKind::Year, parts[5]);
+
+        if parts[2] == "?" && parts[4] == "?" {
+            errors.push(CronError::new(
+                "Day-of-month and Day-of-week cannot both be ?",
+            ));
+        } else if parts[2] != "?" && parts[4] != "?" {
+            errors.push(CronError::new(
+                "Day-of-month and Day-of-week cannot both be set, one of them must be ?",
+            ));
+        }
+
+        let minutes = minutes.map_err(|e| errors.push(e));
+        let hours = hours.map_err(|e| errors.push(e));
+        let day_of_month = day_of_month.map_err(|e| errors.push(e));
+        let months = months.map_err(|e| errors.push(e));
+        let day_of_week = day_of_week.map_err(|e| errors.push(e));
+        let years = years.map_err(|e| errors.push(e));
+
+        match (minutes, hours, day_of_month, months, day_of_week, years) {
+            (Ok(minutes), Ok(hours), Ok(day_of_month), Ok(months), Ok(day_of_week), Ok(years))
+                if errors.is_empty() =>
+            {
+                Ok(Self {
+                    minutes,
+                    hours,
+                    day_of_month,
+                    months,
+                    day_of_week,
+                    years,
+                })
+            }
+            _ => Err(errors),
+        }
+    }
+
+    pub fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.years.contains(&(date.year() as u32)) || !self.months.contains(&date.month()) {
+            return false;
+        }
+
+        let day = date.day();
+        let last_day = last_day_of_month(date);
+        let weekday = date.weekday().number_from_sunday();
+
+        let day_of_month_matches = match &self.day_of_month {
+            DayOfMonth::Any => true,
+            DayOfMonth::Days(days) => days.contains(&day),
+            DayOfMonth::Last => day == last_day,
+            DayOfMonth::LastWeekday => day == nearest_weekday(date, last_day),
+            DayOfMonth::NearestWeekday(target) => {
+                *target <= last_day && day == nearest_weekday(date, *target)
+            }
+        };
+
+        let day_of_week_matches = match &self.day_of_week {
+            DayOfWeek::Any => true,
+            DayOfWeek::Days(days) => days.contains(&weekday),
+            DayOfWeek::Last(target) => weekday == *target && day + 7 > last_day,
+            DayOfWeek::Nth(target, nth) => weekday == *target && (day - 1) / 7 + 1 == *nth,
+        };
+
+        day_of_month_matches && day_of_week_matches
+    }
+
+    // Local times skipped by a DST transition never fire, ambiguous ones fire at the earlier instant
+
+    pub fn next_fire_time(&self, after: DateTime<Utc>, timezone: Tz) -> Option<DateTime<Tz>> {
+        let mut date = after.with_timezone(&timezone).date_naive();
+
+        let max_year = *self.years.last()? as i32;
+
+        while date.year() <= max_year {
+            if !self.years.contains(&(date.year() as u32)) {
+                date = NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)?;
+                continue;
+            }
+
+            if !self.months.contains(&date.month()) {
+                date = date.with_day(1)?.checked_add_months(Months::new(1))?;
+                continue;
+            }
+
+            if self.matches_date(date) {
+                for hour in &self.hours {
+                    for minute in &self.minutes {
+                        let time = NaiveTime::from_hms_opt(*hour, *minute, 0)?;
+
+                        let fire_time = match timezone.from_local_datetime(&date.and_time(time)) {
+                            LocalResult::Single(fire_time) => fire_time,
+                            LocalResult::Ambiguous(earliest, _) => earliest,
+                            LocalResult::None => continue,
+                        };
+
+                        if fire_time > after {
+                            return Some(fire_time);
+                        }
+                    }
+                }
+            }
+
+            date = date.checked_add_days(Days::new(1))?;
+        }
+
+        None
+    }
+
+    pub fn next_fire_times(
+        &self,
+        after: DateTime<Utc>,
+        timezone: Tz,
+        count: usize,
+    ) -> Vec<DateTime<Tz>> {
+        let mut fire_times = Vec::with_capacity(count);
+        let mut after = after;
+
+        while fire_times.len() < count {
+            match self.next_fire_time(after, timezone) {
+                Some(fire_time) => {
+                    after = fire_time.with_timezone(&Utc);
+                    fire_times.push(fire_time);
+                }
+                None => break,
+            }
+        }
+
+        fire_times
+    }
+}
+
+fn last_day_of_month(date: NaiveDate) -> u32 {
+    date.with_day(1)
+        .and_then(|first_day| first_day.checked_add_months(Months::new(1)))
+        .and_then(|next_month| next_month.pred_opt())
+        .map(|last_day| last_day.day())
+        .unwrap_or(28)
+}
+
+// The nearest weekday never moves into the previous or the next month
+
+fn nearest_weekday(date: NaiveDate, target: u32) -> u32 {
+    let last_day = last_day_of_month(date);
+
+    let target_date = match date.with_day(target) {
+        Some(target_date) => target_date,
+        None => return 0,
+    };
+
+    match target_date.weekday().number_from_sunday() {
+        7 if target == 1 => 3,
+        7 => target - 1,
+        1 if target == last_day => target - 2,
+        1 => target + 1,
+        _ => target,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::Europe::{Berlin, Moscow};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn error_fields(expression: &str) -> Vec<Option<CronFieldKind>> {
+        CronExpression::parse(expression)
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.field)
+            .collect()
+    }
+
+    #[test]
+    fn parses_lists_ranges_steps_and_names() {
+        let expression = CronExpression::parse("cron(0/15 9-11 ? JAN,MAR MON-FRI *)").unwrap();
+
+        assert_eq!(expression.minutes, BTreeSet::from([0, 15, 30, 45]));
+        assert_eq!(expression.hours, BTreeSet::from([9, 10, 11]));
+        assert_eq!(expression.day_of_month, DayOfMonth::Any);
+        assert_eq!(expression.months, BTreeSet::from([1, 3]));
+        assert_eq!(
+            expression.day_of_week,
+            DayOfWeek::Days(BTreeSet::from([2, 3, 4, 5, 6]))
+        );
+        assert_eq!(expression.years.len(), 230);
+    }
+
+    #[test]
+    fn rejects_wrong_field_count() {
+        let errors = CronExpression::parse("0 9 * * ?").unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "must have 6 fields, found 5");
+    }
+
+    #[test]
+    fn rejects_both_or_neither_day_fields() {
+        assert_eq!(error_fields("0 9 ? * ? *"), vec![None]);
+        assert_eq!(error_fields("0 9 1 * MON *"), vec![None]);
+    }
+
+    #[test]
+    fn collects_errors_of_every_field() {
+        assert_eq!(
+            error_fields("60 24 ? 13 8 1969"),
+            vec![
+                Some(CronFieldKind::Minutes),
+                Some(CronFieldKind::Hours),
+                Some(CronFieldKind::Month),
+                Some(CronFieldKind::DayOfWeek),
+                Some(CronFieldKind::Year),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_field_errors() {
+        let message =
+            |expression: &str| CronExpression::parse(expression).unwrap_err()[0].to_string();
+
+        assert_eq!(message("x 9 * * ? *"), "Minutes: x is not a valid value");
+        assert_eq!(
+            message("0 9 * * ? 2030-2025"),
+            "Year: range 2030-2025 must not start after its end"
+        );
+        assert_eq!(
+            message("0/0 9 * * ? *"),
+            "Minutes: step 0 must be a positive number"
+        );
+        assert_eq!(
+            message("0 9,,10 * * ? *"),
+            "Hours: list contains an empty value"
+        );
+        assert_eq!(
+            message("0 9 1-5W * ? *"),
+            "Day-of-month: W can only be used with a single day, for example 15W"
+        );
+        assert_eq!(
+            message("0 9 ? * 2#6 *"),
+            "Day-of-week: occurrence 6 must be between 1 and 5"
+        );
+    }
+
+    #[test]
+    fn matches_last_day_of_month() {
+        let expression = CronExpression::parse("0 9 L * ? *").unwrap();
+
+        assert!(expression.matches_date(date(2024, 2, 29)));
+        assert!(!expression.matches_date(date(2025, 2, 27)));
+        assert!(expression.matches_date(date(2025, 2, 28)));
+    }
+
+    #[test]
+    fn matches_last_weekday_of_month() {
+        let expression = CronExpression::parse("0 9 LW * ? *").unwrap();
+
+        // August 31, 2025 is a Sunday
+
+        assert!(expression.matches_date(date(2025, 8, 29)));
+        assert!(!expression.matches_date(date(2025, 8, 31)));
+    }
+
+    #[test]
+    fn matches_nearest_weekday_within_the_month() {
+        let expression = CronExpression::parse("0 9 15W * ? *").unwrap();
+
+        // June 15, 2025 is a Sunday, the nearest weekday is Monday
+
+        assert!(expression.matches_date(date(2025, 6, 16)));
+        assert!(!expression.matches_date(date(2025, 6, 13)));
+
+        // November 1, 2025 is a Saturday, the weekday is taken from the same month
+
+        let expression = CronExpression::parse("0 9 1W * ? *").unwrap();
+
+        assert!(expression.matches_date(date(2025, 11, 3)));
+        assert!(!expression.matches_date(date(2025, 10, 31)));
+    }
+
+    #[test]
+    fn matches_last_and_nth_weekday() {
+        let last_friday = CronExpression::parse("0 9 ? * 6L *").unwrap();
+
+        assert!(last_friday.matches_date(date(2025, 10, 31)));
+        assert!(!last_friday.matches_date(date(2025, 10, 24)));
+
+        let first_monday = CronExpression::parse("0 9 ? * MON#1 *").unwrap();
+
+        assert!(first_monday.matches_date(date(2025, 9, 1)));
+        assert!(!first_monday.matches_date(date(2025, 9, 8)));
+    }
+
+    #[test]
+    fn finds_next_fire_time_in_the_rule_timezone() {
+        let expression = CronExpression::parse("0 9 * * ? *").unwrap();
+
+        assert_eq!(
+            expression
+                .next_fire_time(utc(2025, 3, 12, 9, 0), Moscow)
+                .map(|fire_time| fire_time.with_timezone(&Utc)),
+            Some(utc(2025, 3, 13, 6, 0))
+        );
+
+        assert_eq!(
+            expression
+                .next_fire_times(utc(2025, 3, 12, 5, 0), Moscow, 2)
+                .iter()
+                .map(|fire_time| fire_time.with_timezone(&Utc))
+                .collect::<Vec<_>>(),
+            vec![utc(2025, 3, 12, 6, 0), utc(2025, 3, 13, 6, 0)]
+        );
+    }
+
+    #[test]
+    fn skips_times_missing_after_dst_start() {
+        let expression = CronExpression::parse("30 2 * * ? *").unwrap();
+
+        // 02:30 does not exist in Berlin on March 30, 2025
+
+        assert_eq!(
+            expression
+                .next_fire_time(utc(2025, 3, 29, 12, 0), Berlin)
+                .map(|fire_time| fire_time.with_timezone(&Utc)),
+            Some(utc(2025, 3, 31, 0, 30))
+        );
+    }
+
+    #[test]
+    fn fires_once_at_the_earlier_ambiguous_time_after_dst_end() {
+        let expression = CronExpression::parse("30 2 * * ? *").unwrap();
+
+        // 02:30 happens twice in Berlin on October 26, 2025
+
+        let fire_times = expression
+            .next_fire_times(utc(2025, 10, 25, 12, 0), Berlin, 2)
+            .iter()
+            .map(|fire_time| fire_time.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            fire_times,
+            vec![utc(2025, 10, 26, 0, 30), utc(2025, 10, 27, 1, 30)]
+        );
+    }
+
+    #[test]
+    fn stops_after_the_last_year() {
+        let expression = CronExpression::parse("0 9 * * ? 2024").unwrap();
+
+        assert_eq!(
+            expression.next_fire_time(utc(2025, 1, 1, 0, 0), Moscow),
+            None
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::cron::{
+    expression::{DayOfMonth, DayOfWeek},
+    CronError, CronFieldKind,
+};
+use std::collections::BTreeSet;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const DAY_OF_WEEK_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+// Parses the wildcards shared by every field: *, lists, ranges and steps
+
+pub fn parse_values(kind: CronFieldKind, text: &str) -> Result<BTreeSet<u32>, CronError> {
+    let (min, max) = kind.range();
+    let mut values = BTreeSet::new();
+
+    for item in text.split(',') {
+        if item.is_empty() {
+            return Err(CronError::field(kind, "list contains an empty value"));
+        }
+
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(parse_step(kind, step)?)),
+            None => (item, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let start = parse_value(kind, start)?;
+            let end = parse_value(kind, end)?;
+
+            if start > end {
+                return Err(CronError::field(
+                    kind,
+                    format!("range {} must not start after its end", range),
+                ));
+            }
+
+            (start, end)
+        } else {
+            let start = parse_value(kind, range)?;
+
+            match step {
+                Some(_) => (start, max),
+                None => (start, start),
+            }
+        };
+
+        values.extend((start..=end).step_by(step.unwrap_or(1) as usize));
+    }
+
+    Ok(values)
+}
+
+pub fn parse_day_of_month(text: &str) -> Result<DayOfMonth, CronError> {
+    let kind = CronFieldKind::DayOfMonth;
+
+    match text {
+        "?" => return Ok(DayOfMonth::Any),
+        "L" => return Ok(DayOfMonth::Last),
+        "LW" => return Ok(DayOfMonth::LastWeekday),
+        _ => {}
+    }
+
+    if let Some(day) = text.strip_suffix('W') {
+        if day.contains([',', '-', '/', '*']) {
+            return Err(CronError::field(
+                kind,
+                "W can only be used with a single day, for example 15W",
+            ));
+        }
+
+        return Ok(DayOfMonth::NearestWeekday(parse_value(kind, day)?));
+    }
+
+    if text.contains('L') {
+        return Err(CronError::field(kind, "L must be used on its own or as LW"));
+    }
+
+    Ok(DayOfMonth::Days(parse_values(kind, text)?))
+}
+
+pub fn parse_day_of_week(text: &str) -> Result<DayOfWeek, CronError> {
+    let kind = CronFieldKind::DayOfWeek;
+
+    match text {
+        "?" => return Ok(DayOfWeek::Any),
+        "L" => return Ok(DayOfWeek::Days(BTreeSet::from([7]))),
+        _ => {}
+    }
+
+    if let Some((day, nth)) = text.split_once('#') {
+        let day = parse_value(kind, day)?;
+
+        let nth = nth
+            .parse::<u32>()
+            .ok()
+            .filter(|nth| (1..=5).contains(nth))
+            .ok_or_else(|| {
+                CronError::field(kind, format!("occurrence {} must be between 1 and 5", nth))
+            })?;
+
+        return Ok(DayOfWeek::Nth(day, nth));
+    }
+
+    if let Some(day) = text.strip_suffix('L') {
+        if day.contains([',', '-', '/', '*']) {
+            return Err(CronError::field(
+                kind,
+                "L can only be used with a single day, for example 6L",
+            ));
+        }
+
+        return Ok(DayOfWeek::Last(parse_value(kind, day)?));
+    }
+
+    Ok(DayOfWeek::Days(parse_values(kind, text)?))
+}
+
+fn parse_value(kind: CronFieldKind, text: &str) -> Result<u32, CronError> {
+    let (min, max) = kind.range();
+
+    let names: &[&str] = match kind {
+        CronFieldKind::Month => &MONTH_NAMES,
+        CronFieldKind::DayOfWeek => &DAY_OF_WEEK_NAMES,
+        _ => &[],
+    };
+
+    if let Some(position) = names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(text))
+    {
+        return Ok(position as u32 + min);
+    }
+
+    let value = text
+        .parse::<u32>()
+        .map_err(|_| CronError::field(kind, format!("{} is not a valid value", text)))?;
+
+    if !(min..=max).contains(&value) {
+        return Err(CronError::field(
+            kind,
+            format!("value {} is out of range {}-{}", value, min, max),
+        ));
+    }
+
+    Ok(value)
+}
+
+fn parse_step(kind: CronFieldKind, text: &str) -> Result<u32, CronError> {
+    text.parse::<u32>()
+        .ok()
+        .filter(|step| *step > 0)
+        .ok_or_else(|| CronError::field(kind, format!("step {} must be a positive number", text)))
+}
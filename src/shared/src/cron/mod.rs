@@ -0,0 +1,8 @@
+mod description;
+mod error;
+mod expression;
+mod field;
+
+pub use description::CronLanguage;
+pub use error::{CronError, CronFieldKind};
+pub use expression::{CronExpression, DayOfMonth, DayOfWeek};
@@ -0,0 +1,73 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+const BLACKOUT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+const MAX_SHIFT_DAYS: u64 = 366;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BlackoutCalendar {
+    pub bot_id: String,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub periods: Vec<BlackoutPeriod>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BlackoutPeriod {
+    pub start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl BlackoutCalendar {
+    pub fn find_period(&self, date: NaiveDate) -> Option<&BlackoutPeriod> {
+        self.periods.iter().find(|period| period.contains(date))
+    }
+
+    // Returns None if every day within a year from the given date is blacked out
+
+    pub fn next_allowed_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_SHIFT_DAYS)
+            .filter_map(|days| date.checked_add_days(Days::new(days)))
+            .find(|date| self.find_period(*date).is_none())
+    }
+}
+
+impl BlackoutPeriod {
+    // Periods without an end date cover a single day, malformed dates never match
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        let start_date = match parse_date(&self.start_date) {
+            Some(start_date) => start_date,
+            None => return false,
+        };
+
+        let end_date = match &self.end_date {
+            Some(end_date) => match parse_date(end_date) {
+                Some(end_date) => end_date,
+                None => return false,
+            },
+            None => start_date,
+        };
+
+        (start_date..=end_date).contains(&date)
+    }
+
+    pub fn description(&self) -> String {
+        match (&self.description, &self.end_date) {
+            (Some(description), _) => description.clone(),
+            (None, Some(end_date)) => format!("{} — {}", self.start_date, end_date),
+            (None, None) => self.start_date.clone(),
+        }
+    }
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), BLACKOUT_DATE_FORMAT).ok()
+}
@@ -0,0 +1,5 @@
+mod core;
+mod repository;
+
+pub use core::{BlackoutCalendar, BlackoutPeriod};
+pub use repository::BlackoutCalendarRepository;
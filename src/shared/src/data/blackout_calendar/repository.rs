@@ -0,0 +1,36 @@
+use crate::{aws::errors, data::blackout_calendar::BlackoutCalendar, env};
+use anyhow::Error;
+use aws_sdk_dynamodb::{types::AttributeValue, Client};
+
+pub struct BlackoutCalendarRepository {
+    client: Client,
+    table_name: String,
+}
+
+impl BlackoutCalendarRepository {
+    pub async fn new(dynamodb_client: Client) -> Result<Self, Error> {
+        let table_name = env::get_env_var("BLACKOUT_CALENDARS_TABLE")?;
+
+        Ok(Self {
+            client: dynamodb_client,
+            table_name,
+        })
+    }
+
+    pub async fn get(&self, bot_id: &str, id: &str) -> Result<Option<BlackoutCalendar>, Error> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key("BotId", AttributeValue::S(bot_id.to_string()))
+            .key("Id", AttributeValue::S(id.to_string()))
+            .send()
+            .await
+            .map_err(errors::map_aws_error)?;
+
+        match result.item {
+            Some(item) => Ok(serde_dynamo::from_item(item)?),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,18 +1,34 @@
+mod blackout_calendar;
 mod bot;
 mod poll_action_log;
 mod post;
 mod posting_rule;
+mod queue_item;
 mod scheduler_event;
+mod template_variable;
 
+pub use blackout_calendar::{BlackoutCalendar, BlackoutCalendarRepository, BlackoutPeriod};
 pub use bot::{BotData, BotDataRepository};
 pub use poll_action_log::{
-    PollActionLog, PollActionLogOutput, PollActionLogRecord, PollActionLogRepository,
+    PollActionLog, PollActionLogOutput, PollActionLogRecord, PollActionLogRecordWrite,
+    PollActionLogRepository,
+};
+pub use post::{
+    DocumentPost, MediaGroupPost, MediaGroupPostItem, PhotoPost, PollPost, PollPostOptionResult,
+    Post, PostRepository, PostTrait, TextPost, VideoPost,
 };
-pub use post::{PollPost, Post, PostRepository, PostTrait, TextPost};
 pub use posting_rule::{
-    BasePostingRule, PollPostingRule, PollPostingRuleActionLog, PollPostingRuleActionLogOutput,
+    BasePostingRule, DocumentPostingRule, MediaGroupPostingRule, MediaGroupPostingRuleItem,
+    MediaGroupPostingRuleItemType, PhotoPostingRule, PollPostingRule, PollPostingRuleActionLog,
+    PollPostingRuleActionLogOutput, PollPostingRuleContent,
     PollPostingRuleOptionIntersectionSource, PollPostingRuleOptionIntersectionSourcePostSelector,
-    PollPostingRuleOptionSource, PollPostingRuleOptionSourcesNoResultsBehavior, PostingRule,
-    PostingRuleRepository, PostingRuleTrait, TextPostingRule,
+    PollPostingRuleOptionSource, PollPostingRuleOptionSourcesNoResultsBehavior,
+    PollPostingRulePollType, PollPostingRuleResultsSummary, PollPostingRuleResultsSummaryTarget,
+    PostingRule, PostingRuleBlackout, PostingRuleBlackoutBehavior, PostingRuleMedia,
+    PostingRuleRepository, PostingRuleSchedule, PostingRuleSkip, PostingRuleTrait,
+    QueuePostingRule, QueuePostingRuleContent, QueuePostingRuleEmptyQueueBehavior, TextPostingRule,
+    TextPostingRuleContent, TextPostingRuleVariantSelection, VideoPostingRule,
 };
-pub use scheduler_event::SchedulerEvent;
+pub use queue_item::{QueueItem, QueueItemRepository};
+pub use scheduler_event::{SchedulerEvent, SchedulerEventAction, SCHEDULED_TIME_PLACEHOLDER};
+pub use template_variable::{TemplateVariable, TemplateVariableRepository};
@@ -5,6 +5,7 @@ use crate::{
     date,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use teloxide::types::{ChatId, MessageId, PollId, UpdateId, User};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +21,7 @@ pub struct PollActionLog {
     pub action_log_topic_id: Option<i32>,
     pub action_log_message_id: i32,
     pub output: PollActionLogOutput,
+    #[serde(default, skip_serializing)]
     pub records: Vec<PollActionLogRecord>,
     pub timezone: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,7 +30,7 @@ pub struct PollActionLog {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", from = "PollActionLogRecordData")]
 pub struct PollActionLogRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub update_id: Option<UpdateId>,
@@ -38,13 +40,31 @@ pub struct PollActionLogRecord {
     pub actor_last_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actor_username: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub option_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub option_text: Option<String>,
+    pub option_ids: Vec<i32>,
+    pub option_texts: Vec<String>,
     pub timestamp: i64,
 }
 
+// Records written before multi-answer support hold a single OptionId/OptionText pair,
+// they are converted to the list form on read
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PollActionLogRecordData {
+    update_id: Option<UpdateId>,
+    actor_id: u64,
+    actor_first_name: String,
+    actor_last_name: Option<String>,
+    actor_username: Option<String>,
+    #[serde(default)]
+    option_ids: Vec<i32>,
+    #[serde(default)]
+    option_texts: Vec<String>,
+    option_id: Option<i32>,
+    option_text: Option<String>,
+    timestamp: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "Type", rename_all = "PascalCase")]
 pub enum PollActionLogOutput {
@@ -120,14 +140,50 @@ impl PollActionLog {
     pub fn action_log_message_id(&self) -> MessageId {
         MessageId(self.action_log_message_id)
     }
+
+    pub fn latest_records_by_actor(&self) -> HashMap<u64, PollActionLogRecord> {
+        self.records
+            .iter()
+            .fold(HashMap::new(), |mut latest_by_actor, record| {
+                latest_by_actor
+                    .entry(record.actor_id)
+                    .and_modify(|existing: &mut PollActionLogRecord| {
+                        if record.timestamp > existing.timestamp {
+                            *existing = record.clone();
+                        }
+                    })
+                    .or_insert_with(|| record.clone());
+                latest_by_actor
+            })
+    }
+
+    pub fn contains_update(&self, update_id: UpdateId) -> bool {
+        self.records
+            .iter()
+            .any(|record| record.update_id == Some(update_id))
+    }
 }
 
 impl PollActionLogRecord {
+    pub fn actor_name(&self) -> String {
+        let last_name = self
+            .actor_last_name
+            .clone()
+            .map_or("".to_string(), |ln| format!(" {}", ln));
+
+        let username = self
+            .actor_username
+            .clone()
+            .map_or("".to_string(), |un| format!(" (@{})", un));
+
+        format!("{}{}{}", self.actor_first_name, last_name, username)
+    }
+
     pub fn new(
         update_id: UpdateId,
         user: &User,
-        option_id: Option<i32>,
-        option_text: Option<String>,
+        option_ids: Vec<i32>,
+        option_texts: Vec<String>,
     ) -> Self {
         let timestamp = chrono::Utc::now().timestamp();
 
@@ -137,9 +193,43 @@ impl PollActionLogRecord {
             actor_first_name: user.first_name.clone(),
             actor_last_name: user.last_name.clone(),
             actor_username: user.username.clone(),
-            option_id,
-            option_text,
+            option_ids,
+            option_texts,
             timestamp,
         }
     }
+
+    pub fn is_revoked(&self) -> bool {
+        self.option_ids.is_empty()
+    }
+
+    pub fn has_option(&self, option_id: i32) -> bool {
+        self.option_ids.contains(&option_id)
+    }
+}
+
+impl From<PollActionLogRecordData> for PollActionLogRecord {
+    fn from(data: PollActionLogRecordData) -> Self {
+        let mut option_ids = data.option_ids;
+        let mut option_texts = data.option_texts;
+
+        if option_ids.is_empty() {
+            option_ids.extend(data.option_id);
+        }
+
+        if option_texts.is_empty() {
+            option_texts.extend(data.option_text);
+        }
+
+        PollActionLogRecord {
+            update_id: data.update_id,
+            actor_id: data.actor_id,
+            actor_first_name: data.actor_first_name,
+            actor_last_name: data.actor_last_name,
+            actor_username: data.actor_username,
+            option_ids,
+            option_texts,
+            timestamp: data.timestamp,
+        }
+    }
 }
@@ -2,4 +2,4 @@ mod core;
 mod repository;
 
 pub use core::{PollActionLog, PollActionLogOutput, PollActionLogRecord};
-pub use repository::PollActionLogRepository;
+pub use repository::{PollActionLogRecordWrite, PollActionLogRepository};
@@ -1,20 +1,41 @@
-use crate::{aws::errors, data::poll_action_log::PollActionLog, env};
-use anyhow::Error;
-use aws_sdk_dynamodb::{types::AttributeValue, Client};
+use crate::{
+    aws::errors,
+    data::poll_action_log::{PollActionLog, PollActionLogRecord},
+    env,
+};
+use anyhow::{anyhow, Error};
+use aws_sdk_dynamodb::{
+    operation::transact_write_items::TransactWriteItemsError,
+    types::{AttributeValue, Put, TransactWriteItem, Update},
+    Client,
+};
+use std::collections::HashMap;
 use tracing::warn;
 
+// Records are stored as separate items in the records table keyed by PollId and UpdateId,
+// logs created before the split still keep their records inside the log item and are merged on read
+
+pub enum PollActionLogRecordWrite {
+    Stored,
+    AlreadyExists,
+    Conflict,
+}
+
 pub struct PollActionLogRepository {
     client: Client,
     table_name: String,
+    records_table_name: String,
 }
 
 impl PollActionLogRepository {
     pub async fn new(dynamodb_client: Client) -> Result<Self, Error> {
         let table_name = env::get_env_var("POLL_ACTION_LOG_TABLE")?;
+        let records_table_name = env::get_env_var("POLL_ACTION_LOG_RECORDS_TABLE")?;
 
         Ok(Self {
             client: dynamodb_client,
             table_name,
+            records_table_name,
         })
     }
 
@@ -29,7 +50,9 @@ impl PollActionLogRepository {
             .map_err(errors::map_aws_error)?;
 
         match result.item {
-            Some(item) => Ok(serde_dynamo::from_item(item)?),
+            Some(item) => Ok(Some(
+                self.with_records(serde_dynamo::from_item(item)?).await?,
+            )),
             None => Ok(None),
         }
     }
@@ -53,21 +76,53 @@ impl PollActionLogRepository {
             .map_err(errors::map_aws_error)?;
 
         match result.items {
-            Some(items) if !items.is_empty() => Ok(Some(serde_dynamo::from_item(
-                items.into_iter().next().unwrap(),
-            )?)),
+            Some(items) if !items.is_empty() => {
+                let action_log = serde_dynamo::from_item(items.into_iter().next().unwrap())?;
+                Ok(Some(self.with_records(action_log).await?))
+            }
             _ => Ok(None),
         }
     }
 
-    // TODO: Use the result of this method to implement optimistic locking and handle conflicts in the caller
+    pub async fn get_records(&self, poll_id: &str) -> Result<Vec<PollActionLogRecord>, Error> {
+        let mut records = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.records_table_name)
+                .key_condition_expression("PollId = :poll_id")
+                .expression_attribute_values(":poll_id", AttributeValue::S(poll_id.to_string()))
+                .consistent_read(true)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(errors::map_aws_error)?;
+
+            for item in result.items.unwrap_or_default() {
+                records.push(serde_dynamo::from_item(item)?);
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(records)
+    }
 
-    pub async fn put(&self, item: &PollActionLog) -> Result<bool, Error> {
+    // Logs are only written when a poll is posted, a conflict means the log already exists
+
+    pub async fn put(&self, item: &PollActionLog) -> Result<(), Error> {
         let current_version = item.version;
 
         let mut item = item.clone();
         item.version += 1;
 
+        let poll_id = item.id.clone();
         let item = serde_dynamo::to_item(item)?;
 
         let result = self
@@ -81,12 +136,15 @@ impl PollActionLogRepository {
             .await;
 
         match result {
-            Ok(_) => Ok(true),
+            Ok(_) => Ok(()),
             Err(err) => {
                 if let Some(service_error) = err.as_service_error() {
                     if service_error.is_conditional_check_failed_exception() {
-                        warn!("Conflict: version mismatch");
-                        return Ok(false);
+                        return Err(anyhow!(
+                            "Poll action log {} was modified concurrently, version: {}",
+                            poll_id,
+                            current_version
+                        ));
                     }
                 }
 
@@ -94,4 +152,100 @@ impl PollActionLogRepository {
             }
         }
     }
+
+    // The record is written together with a Version bump of the log so that it is only stored while the log exists,
+    // concurrent answers to the same poll cancel each other's transaction and are reported as a conflict
+
+    pub async fn put_record(
+        &self,
+        action_log: &PollActionLog,
+        record: &PollActionLogRecord,
+    ) -> Result<PollActionLogRecordWrite, Error> {
+        if record.update_id.is_none() {
+            return Err(anyhow!(
+                "Poll action log record without UpdateId cannot be stored, poll_id: {}",
+                action_log.id
+            ));
+        }
+
+        let mut item: HashMap<String, AttributeValue> = serde_dynamo::to_item(record)?;
+
+        item.insert(
+            "PollId".to_string(),
+            AttributeValue::S(action_log.id.clone()),
+        );
+
+        if let Some(expires_at) = action_log.expires_at {
+            item.insert(
+                "ExpiresAt".to_string(),
+                AttributeValue::N(expires_at.to_string()),
+            );
+        }
+
+        let update = Update::builder()
+            .table_name(&self.table_name)
+            .key("Id", AttributeValue::S(action_log.id.clone()))
+            .update_expression("SET Version = Version + :one")
+            .condition_expression("attribute_exists(Id)")
+            .expression_attribute_values(":one", AttributeValue::N("1".to_string()))
+            .build()?;
+
+        let put = Put::builder()
+            .table_name(&self.records_table_name)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(UpdateId)")
+            .build()?;
+
+        let result = self
+            .client
+            .transact_write_items()
+            .transact_items(TransactWriteItem::builder().update(update).build())
+            .transact_items(TransactWriteItem::builder().put(put).build())
+            .send()
+            .await;
+
+        let err = match result {
+            Ok(_) => return Ok(PollActionLogRecordWrite::Stored),
+            Err(err) => err,
+        };
+
+        if let Some(TransactWriteItemsError::TransactionCanceledException(canceled)) =
+            err.as_service_error()
+        {
+            let codes = canceled
+                .cancellation_reasons()
+                .iter()
+                .map(|reason| reason.code().unwrap_or("None"))
+                .collect::<Vec<_>>();
+
+            match codes.as_slice() {
+                ["ConditionalCheckFailed", _] => {
+                    return Err(anyhow!("Poll action log {} not found", action_log.id));
+                }
+                [_, "ConditionalCheckFailed"] => {
+                    warn!(poll_id = %action_log.id, "Record with the same UpdateId already exists");
+                    return Ok(PollActionLogRecordWrite::AlreadyExists);
+                }
+                _ if codes.contains(&"TransactionConflict") => {
+                    return Ok(PollActionLogRecordWrite::Conflict);
+                }
+                _ => {}
+            }
+        }
+
+        Err(errors::map_aws_error(err))
+    }
+
+    async fn with_records(&self, action_log: PollActionLog) -> Result<PollActionLog, Error> {
+        let mut action_log = action_log;
+
+        let records = self.get_records(&action_log.id).await?;
+
+        action_log.records.extend(records);
+        action_log
+            .records
+            .sort_by_key(|record| record.update_id.map(|update_id| update_id.0));
+
+        Ok(action_log)
+    }
 }
@@ -16,13 +16,28 @@ pub struct BasePost {
     pub is_pinned: bool,
     pub timestamp: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<i64>,
+    #[serde(default)]
+    pub delete_on_expire: bool,
 }
 
 impl BasePost {
     pub fn new(posting_rule: &impl PostingRuleTrait, message_id: i32, timestamp: i64) -> Self {
         let expires_at = posting_rule.ttl_hours().map(date::calculate_expires_at);
 
+        let schedule = if posting_rule.schedules().is_empty() {
+            posting_rule.base().schedule.clone()
+        } else {
+            posting_rule
+                .schedules()
+                .iter()
+                .map(|entry| entry.schedule.clone())
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+
         BasePost {
             chat_id: posting_rule.base().chat_id,
             topic_id: posting_rule.base().topic_id,
@@ -30,11 +45,13 @@ impl BasePost {
             bot_id: posting_rule.base().bot_id.clone(),
             posting_rule_id: posting_rule.base().id.clone(),
             posting_rule_name: posting_rule.name().to_string(),
-            schedule: posting_rule.base().schedule.clone(),
+            schedule,
             timezone: posting_rule.base().timezone.clone(),
             is_pinned: posting_rule.base().should_pin,
             timestamp,
+            scheduled_at: None,
             expires_at,
+            delete_on_expire: posting_rule.base().delete_on_expire,
         }
     }
 }
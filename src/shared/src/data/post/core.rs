@@ -1,5 +1,5 @@
 use crate::data::{
-    post::{PollPost, TextPost},
+    post::{DocumentPost, MediaGroupPost, PhotoPost, PollPost, TextPost, VideoPost},
     PostTrait,
 };
 use serde::{Deserialize, Serialize};
@@ -9,6 +9,36 @@ use serde::{Deserialize, Serialize};
 pub enum Post {
     Text(TextPost),
     Poll(PollPost),
+    Photo(PhotoPost),
+    Video(VideoPost),
+    Document(DocumentPost),
+    MediaGroup(MediaGroupPost),
+}
+
+impl Post {
+    pub fn set_pinned(&mut self, is_pinned: bool) {
+        match self {
+            Post::Text(text_post) => text_post.base.is_pinned = is_pinned,
+            Post::Poll(poll_post) => poll_post.base.is_pinned = is_pinned,
+            Post::Photo(photo_post) => photo_post.base.is_pinned = is_pinned,
+            Post::Video(video_post) => video_post.base.is_pinned = is_pinned,
+            Post::Document(document_post) => document_post.base.is_pinned = is_pinned,
+            Post::MediaGroup(media_group_post) => media_group_post.base.is_pinned = is_pinned,
+        }
+    }
+
+    pub fn set_scheduled_at(&mut self, scheduled_at: i64) {
+        match self {
+            Post::Text(text_post) => text_post.base.scheduled_at = Some(scheduled_at),
+            Post::Poll(poll_post) => poll_post.base.scheduled_at = Some(scheduled_at),
+            Post::Photo(photo_post) => photo_post.base.scheduled_at = Some(scheduled_at),
+            Post::Video(video_post) => video_post.base.scheduled_at = Some(scheduled_at),
+            Post::Document(document_post) => document_post.base.scheduled_at = Some(scheduled_at),
+            Post::MediaGroup(media_group_post) => {
+                media_group_post.base.scheduled_at = Some(scheduled_at)
+            }
+        }
+    }
 }
 
 impl PostTrait for Post {
@@ -16,6 +46,10 @@ impl PostTrait for Post {
         match self {
             Post::Text(text_post) => text_post.base(),
             Post::Poll(poll_post) => poll_post.base(),
+            Post::Photo(photo_post) => photo_post.base(),
+            Post::Video(video_post) => video_post.base(),
+            Post::Document(document_post) => document_post.base(),
+            Post::MediaGroup(media_group_post) => media_group_post.base(),
         }
     }
 }
@@ -0,0 +1,45 @@
+use crate::data::{
+    post::{BasePost, PostTrait},
+    posting_rule::DocumentPostingRule,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DocumentPost {
+    #[serde(flatten)]
+    pub base: BasePost,
+    pub content: DocumentPostContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DocumentPostContent {
+    pub file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl DocumentPost {
+    pub fn new(
+        document_posting_rule: &DocumentPostingRule,
+        message_id: i32,
+        timestamp: i64,
+        file_id: &str,
+        caption: Option<&str>,
+    ) -> Self {
+        let base = BasePost::new(document_posting_rule, message_id, timestamp);
+        let content = DocumentPostContent {
+            file_id: file_id.to_string(),
+            caption: caption.map(|caption| caption.to_string()),
+        };
+
+        DocumentPost { base, content }
+    }
+}
+
+impl PostTrait for DocumentPost {
+    fn base(&self) -> &BasePost {
+        &self.base
+    }
+}
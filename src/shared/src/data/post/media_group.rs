@@ -0,0 +1,52 @@
+use crate::data::{
+    post::{BasePost, PostTrait},
+    posting_rule::{MediaGroupPostingRule, MediaGroupPostingRuleItemType},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPost {
+    #[serde(flatten)]
+    pub base: BasePost,
+    pub content: MediaGroupPostContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPostContent {
+    pub message_ids: Vec<i32>,
+    pub items: Vec<MediaGroupPostItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPostItem {
+    pub media_type: MediaGroupPostingRuleItemType,
+    pub file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl MediaGroupPost {
+    pub fn new(
+        media_group_posting_rule: &MediaGroupPostingRule,
+        message_ids: &[i32],
+        timestamp: i64,
+        items: Vec<MediaGroupPostItem>,
+    ) -> Self {
+        let base = BasePost::new(media_group_posting_rule, message_ids[0], timestamp);
+        let content = MediaGroupPostContent {
+            message_ids: message_ids.to_vec(),
+            items,
+        };
+
+        MediaGroupPost { base, content }
+    }
+}
+
+impl PostTrait for MediaGroupPost {
+    fn base(&self) -> &BasePost {
+        &self.base
+    }
+}
@@ -1,13 +1,21 @@
 mod base;
 mod core;
+mod document;
+mod media_group;
+mod photo;
 mod poll;
 mod repository;
 mod text;
 mod traits;
+mod video;
 
 pub use base::BasePost;
 pub use core::Post;
-pub use poll::PollPost;
+pub use document::DocumentPost;
+pub use media_group::{MediaGroupPost, MediaGroupPostItem};
+pub use photo::PhotoPost;
+pub use poll::{PollPost, PollPostOptionResult};
 pub use repository::PostRepository;
 pub use text::TextPost;
 pub use traits::PostTrait;
+pub use video::VideoPost;
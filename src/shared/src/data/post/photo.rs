@@ -0,0 +1,45 @@
+use crate::data::{
+    post::{BasePost, PostTrait},
+    posting_rule::PhotoPostingRule,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PhotoPost {
+    #[serde(flatten)]
+    pub base: BasePost,
+    pub content: PhotoPostContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PhotoPostContent {
+    pub file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl PhotoPost {
+    pub fn new(
+        photo_posting_rule: &PhotoPostingRule,
+        message_id: i32,
+        timestamp: i64,
+        file_id: &str,
+        caption: Option<&str>,
+    ) -> Self {
+        let base = BasePost::new(photo_posting_rule, message_id, timestamp);
+        let content = PhotoPostContent {
+            file_id: file_id.to_string(),
+            caption: caption.map(|caption| caption.to_string()),
+        };
+
+        PhotoPost { base, content }
+    }
+}
+
+impl PostTrait for PhotoPost {
+    fn base(&self) -> &BasePost {
+        &self.base
+    }
+}
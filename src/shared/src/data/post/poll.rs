@@ -17,6 +17,19 @@ pub struct PollPost {
 pub struct PollPostContent {
     pub question: String,
     pub options: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_schedule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<PollPostOptionResult>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PollPostOptionResult {
+    pub text: String,
+    pub voter_count: u32,
 }
 
 impl PollPost {
@@ -31,12 +44,26 @@ impl PollPost {
         let content = PollPostContent {
             question: question.to_string(),
             options: options.to_vec(),
+            close_schedule: poll_posting_rule.close_schedule.clone(),
+            closed_at: None,
+            results: None,
         };
 
         PollPost { base, content }
     }
 }
 
+impl PollPost {
+    pub fn is_closed(&self) -> bool {
+        self.content.closed_at.is_some()
+    }
+
+    pub fn close(&mut self, closed_at: i64, results: Vec<PollPostOptionResult>) {
+        self.content.closed_at = Some(closed_at);
+        self.content.results = Some(results);
+    }
+}
+
 impl PostTrait for PollPost {
     fn base(&self) -> &BasePost {
         &self.base
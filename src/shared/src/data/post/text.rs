@@ -1,6 +1,6 @@
 use crate::data::{
     post::{BasePost, PostTrait},
-    posting_rule::TextPostingRule,
+    PostingRuleTrait,
 };
 use serde::{Deserialize, Serialize};
 
@@ -16,18 +16,22 @@ pub struct TextPost {
 #[serde(rename_all = "PascalCase")]
 pub struct TextPostContent {
     pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_index: Option<usize>,
 }
 
 impl TextPost {
     pub fn new(
-        text_posting_rule: &TextPostingRule,
+        posting_rule: &impl PostingRuleTrait,
         message_id: i32,
         timestamp: i64,
         text: &str,
+        variant_index: Option<usize>,
     ) -> Self {
-        let base = BasePost::new(text_posting_rule, message_id, timestamp);
+        let base = BasePost::new(posting_rule, message_id, timestamp);
         let content = TextPostContent {
             text: text.to_string(),
+            variant_index,
         };
 
         TextPost { base, content }
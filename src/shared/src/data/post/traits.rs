@@ -44,7 +44,15 @@ pub trait PostTrait {
         self.base().timestamp
     }
 
+    fn scheduled_at(&self) -> Option<i64> {
+        self.base().scheduled_at
+    }
+
     fn expires_at(&self) -> Option<i64> {
         self.base().expires_at
     }
+
+    fn delete_on_expire(&self) -> bool {
+        self.base().delete_on_expire
+    }
 }
@@ -0,0 +1,45 @@
+use crate::data::{
+    post::{BasePost, PostTrait},
+    posting_rule::VideoPostingRule,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VideoPost {
+    #[serde(flatten)]
+    pub base: BasePost,
+    pub content: VideoPostContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VideoPostContent {
+    pub file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl VideoPost {
+    pub fn new(
+        video_posting_rule: &VideoPostingRule,
+        message_id: i32,
+        timestamp: i64,
+        file_id: &str,
+        caption: Option<&str>,
+    ) -> Self {
+        let base = BasePost::new(video_posting_rule, message_id, timestamp);
+        let content = VideoPostContent {
+            file_id: file_id.to_string(),
+            caption: caption.map(|caption| caption.to_string()),
+        };
+
+        VideoPost { base, content }
+    }
+}
+
+impl PostTrait for VideoPost {
+    fn base(&self) -> &BasePost {
+        &self.base
+    }
+}
@@ -1,3 +1,7 @@
+use crate::{
+    data::posting_rule::{PostingRuleBlackout, PostingRuleSchedule, PostingRuleSkip},
+    template::Locale,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,12 +15,35 @@ pub struct BasePostingRule {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(default)]
     pub schedule: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedules: Vec<PostingRuleSchedule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blackout: Option<PostingRuleBlackout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_skip: Option<PostingRuleSkip>,
     pub timezone: String,
     #[serde(default)]
+    pub locale: Locale,
+    #[serde(default)]
     pub should_pin: bool,
     #[serde(default)]
+    pub unpin_previous: bool,
+    #[serde(default)]
     pub is_active: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl_hours: Option<i64>,
+    #[serde(default)]
+    pub delete_on_expire: bool,
+    #[serde(default)]
+    pub propagate_edits: bool,
 }
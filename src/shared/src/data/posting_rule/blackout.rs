@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostingRuleBlackout {
+    pub calendar_id: String,
+    pub behavior: PostingRuleBlackoutBehavior,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum PostingRuleBlackoutBehavior {
+    Skip,
+    ShiftToNextAllowedDay,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostingRuleSkip {
+    pub timestamp: i64,
+    pub reason: String,
+}
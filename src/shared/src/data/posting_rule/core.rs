@@ -1,8 +1,10 @@
 use crate::data::{
     posting_rule::{validation::PostingRuleValidator, BasePostingRule},
-    PollPostingRule, PostingRuleTrait, TextPostingRule,
+    DocumentPostingRule, MediaGroupPostingRule, PhotoPostingRule, PollPostingRule,
+    PostingRuleTrait, QueuePostingRule, TextPostingRule, VideoPostingRule,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // TODO: Add proper repository structures for data types and create DynamoDB only once at the Cold Start
 
@@ -11,12 +13,21 @@ use serde::{Deserialize, Serialize};
 pub enum PostingRule {
     Text(TextPostingRule),
     Poll(PollPostingRule),
+    Photo(PhotoPostingRule),
+    Video(VideoPostingRule),
+    Document(DocumentPostingRule),
+    MediaGroup(MediaGroupPostingRule),
+    Queue(QueuePostingRule),
 }
 
 impl PostingRule {
     pub fn is_valid(&self) -> bool {
         PostingRuleValidator::validate(self).is_empty()
     }
+
+    pub fn undefined_variables(&self, variables: &HashMap<String, String>) -> Vec<String> {
+        PostingRuleValidator::validate_variables(self, variables)
+    }
 }
 
 impl PostingRuleTrait for PostingRule {
@@ -24,6 +35,11 @@ impl PostingRuleTrait for PostingRule {
         match self {
             PostingRule::Text(rule) => rule.base(),
             PostingRule::Poll(rule) => rule.base(),
+            PostingRule::Photo(rule) => rule.base(),
+            PostingRule::Video(rule) => rule.base(),
+            PostingRule::Document(rule) => rule.base(),
+            PostingRule::MediaGroup(rule) => rule.base(),
+            PostingRule::Queue(rule) => rule.base(),
         }
     }
 
@@ -31,6 +47,23 @@ impl PostingRuleTrait for PostingRule {
         match self {
             PostingRule::Text(rule) => rule.set_active(active),
             PostingRule::Poll(rule) => rule.set_active(active),
+            PostingRule::Photo(rule) => rule.set_active(active),
+            PostingRule::Video(rule) => rule.set_active(active),
+            PostingRule::Document(rule) => rule.set_active(active),
+            PostingRule::MediaGroup(rule) => rule.set_active(active),
+            PostingRule::Queue(rule) => rule.set_active(active),
+        }
+    }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        match self {
+            PostingRule::Text(rule) => rule.set_completed(completed_at),
+            PostingRule::Poll(rule) => rule.set_completed(completed_at),
+            PostingRule::Photo(rule) => rule.set_completed(completed_at),
+            PostingRule::Video(rule) => rule.set_completed(completed_at),
+            PostingRule::Document(rule) => rule.set_completed(completed_at),
+            PostingRule::MediaGroup(rule) => rule.set_completed(completed_at),
+            PostingRule::Queue(rule) => rule.set_completed(completed_at),
         }
     }
 }
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum PostingRuleMedia {
+    FileId {
+        #[serde(rename = "FileId")]
+        file_id: String,
+    },
+    Url {
+        #[serde(rename = "Url")]
+        url: String,
+    },
+}
@@ -0,0 +1,50 @@
+use crate::data::{
+    posting_rule::{BasePostingRule, PostingRuleMedia},
+    PostingRuleTrait,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPostingRule {
+    #[serde(flatten)]
+    base: BasePostingRule,
+    pub content: MediaGroupPostingRuleContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPostingRuleContent {
+    pub items: Vec<MediaGroupPostingRuleItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaGroupPostingRuleItem {
+    pub media_type: MediaGroupPostingRuleItemType,
+    pub media: PostingRuleMedia,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub enum MediaGroupPostingRuleItemType {
+    Photo,
+    Video,
+    Document,
+}
+
+impl PostingRuleTrait for MediaGroupPostingRule {
+    fn base(&self) -> &BasePostingRule {
+        &self.base
+    }
+
+    fn set_active(&mut self, active: bool) {
+        self.base.is_active = active;
+    }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        self.base.completed_at = completed_at;
+    }
+}
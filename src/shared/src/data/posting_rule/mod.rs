@@ -1,18 +1,38 @@
 mod base;
+mod blackout;
 mod core;
+mod document;
+mod media;
+mod media_group;
+mod photo;
 mod poll;
+mod queue;
 mod repository;
+mod schedule;
 mod text;
 mod traits;
 mod validation;
+mod video;
 
 pub use base::BasePostingRule;
+pub use blackout::{PostingRuleBlackout, PostingRuleBlackoutBehavior, PostingRuleSkip};
 pub use core::PostingRule;
+pub use document::DocumentPostingRule;
+pub use media::PostingRuleMedia;
+pub use media_group::{
+    MediaGroupPostingRule, MediaGroupPostingRuleItem, MediaGroupPostingRuleItemType,
+};
+pub use photo::PhotoPostingRule;
 pub use poll::{
     PollPostingRule, PollPostingRuleActionLog, PollPostingRuleActionLogOutput,
-    PollPostingRuleOptionIntersectionSource, PollPostingRuleOptionIntersectionSourcePostSelector,
-    PollPostingRuleOptionSource, PollPostingRuleOptionSourcesNoResultsBehavior,
+    PollPostingRuleContent, PollPostingRuleOptionIntersectionSource,
+    PollPostingRuleOptionIntersectionSourcePostSelector, PollPostingRuleOptionSource,
+    PollPostingRuleOptionSourcesNoResultsBehavior, PollPostingRulePollType,
+    PollPostingRuleResultsSummary, PollPostingRuleResultsSummaryTarget,
 };
+pub use queue::{QueuePostingRule, QueuePostingRuleContent, QueuePostingRuleEmptyQueueBehavior};
 pub use repository::PostingRuleRepository;
-pub use text::TextPostingRule;
+pub use schedule::PostingRuleSchedule;
+pub use text::{TextPostingRule, TextPostingRuleContent, TextPostingRuleVariantSelection};
 pub use traits::PostingRuleTrait;
+pub use video::VideoPostingRule;
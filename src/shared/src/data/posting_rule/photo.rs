@@ -0,0 +1,35 @@
+use crate::data::{
+    posting_rule::{BasePostingRule, PostingRuleMedia},
+    PostingRuleTrait,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PhotoPostingRule {
+    #[serde(flatten)]
+    base: BasePostingRule,
+    pub content: PhotoPostingRuleContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PhotoPostingRuleContent {
+    pub media: PostingRuleMedia,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl PostingRuleTrait for PhotoPostingRule {
+    fn base(&self) -> &BasePostingRule {
+        &self.base
+    }
+
+    fn set_active(&mut self, active: bool) {
+        self.base.is_active = active;
+    }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        self.base.completed_at = completed_at;
+    }
+}
@@ -10,6 +10,10 @@ pub struct PollPostingRule {
     pub content: PollPostingRuleContent,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_log: Option<PollPostingRuleActionLog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_schedule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results_summary: Option<PollPostingRuleResultsSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +23,30 @@ pub struct PollPostingRuleContent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub option_sourcing: Option<PollPostingRuleOptionSourcing>,
     pub options: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_type: Option<PollPostingRulePollType>,
+    #[serde(default)]
+    pub allows_multiple_answers: bool,
+    #[serde(default)]
+    pub is_anonymous: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<u16>,
+    // Telegram only accepts a close date up to 600 seconds ahead, so the poll is closed by a one-time
+    // close schedule instead of the send request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum PollPostingRulePollType {
+    Regular,
+    Quiz {
+        #[serde(rename = "CorrectOptionId")]
+        correct_option_id: u8,
+        #[serde(rename = "Explanation", skip_serializing_if = "Option::is_none")]
+        explanation: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,6 +108,21 @@ pub enum PollPostingRuleActionLogOutput {
     },
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PollPostingRuleResultsSummary {
+    pub target: PollPostingRuleResultsSummaryTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum PollPostingRuleResultsSummaryTarget {
+    SameChat,
+    ActionLogChat,
+}
+
 impl PollPostingRuleActionLog {
     pub fn chat_id(&self) -> ChatId {
         ChatId(self.chat_id)
@@ -98,4 +141,8 @@ impl PostingRuleTrait for PollPostingRule {
     fn set_active(&mut self, active: bool) {
         self.base.is_active = active;
     }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        self.base.completed_at = completed_at;
+    }
 }
@@ -0,0 +1,44 @@
+use crate::data::{posting_rule::BasePostingRule, PostingRuleTrait};
+use serde::{Deserialize, Serialize};
+
+// The text of each post is taken from the queue items of the rule, see QueueItemRepository
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QueuePostingRule {
+    #[serde(flatten)]
+    base: BasePostingRule,
+    pub content: QueuePostingRuleContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QueuePostingRuleContent {
+    #[serde(default)]
+    pub empty_queue_behavior: QueuePostingRuleEmptyQueueBehavior,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum QueuePostingRuleEmptyQueueBehavior {
+    #[default]
+    Deactivate,
+    NotifyAdmins {
+        #[serde(rename = "ChatId")]
+        chat_id: i64,
+    },
+}
+
+impl PostingRuleTrait for QueuePostingRule {
+    fn base(&self) -> &BasePostingRule {
+        &self.base
+    }
+
+    fn set_active(&mut self, active: bool) {
+        self.base.is_active = active;
+    }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        self.base.completed_at = completed_at;
+    }
+}
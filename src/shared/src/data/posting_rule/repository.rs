@@ -1,4 +1,8 @@
-use crate::{aws::errors, data::PostingRule, env};
+use crate::{
+    aws::errors,
+    data::{PostingRule, PostingRuleSkip},
+    env,
+};
 use anyhow::Error;
 use aws_sdk_dynamodb::{types::AttributeValue, Client};
 
@@ -52,13 +56,98 @@ impl PostingRuleRepository {
         Ok(items)
     }
 
-    pub async fn put_item(&self, item: &PostingRule) -> Result<(), Error> {
-        let item = serde_dynamo::to_item(item)?;
+    // A rule that is activated again starts over, a completion left on it would keep its schedules deleted
 
-        self.client
-            .put_item()
+    pub async fn set_active(&self, id: &str, active: bool) -> Result<(), Error> {
+        let remove: &[&str] = if active { &["CompletedAt"] } else { &[] };
+
+        self.update_attributes(id, vec![("IsActive", AttributeValue::Bool(active))], remove)
+            .await
+    }
+
+    pub async fn set_completed(&self, id: &str, completed_at: i64) -> Result<(), Error> {
+        self.update_attributes(
+            id,
+            vec![
+                ("CompletedAt", AttributeValue::N(completed_at.to_string())),
+                ("IsActive", AttributeValue::Bool(false)),
+            ],
+            &[],
+        )
+        .await
+    }
+
+    // Rotation state is kept outside of Content, changing it must not be taken for an edit of the post
+
+    pub async fn set_recent_variant_indices(
+        &self,
+        id: &str,
+        indices: &[usize],
+    ) -> Result<(), Error> {
+        self.update_attributes(
+            id,
+            vec![(
+                "RecentVariantIndices",
+                serde_dynamo::to_attribute_value(indices)?,
+            )],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn set_last_skip(&self, id: &str, last_skip: &PostingRuleSkip) -> Result<(), Error> {
+        self.update_attributes(
+            id,
+            vec![("LastSkip", serde_dynamo::to_attribute_value(last_skip)?)],
+            &[],
+        )
+        .await
+    }
+
+    // Changes single attributes instead of writing the whole item, so that a copy of the rule read
+    // at the start of an invocation does not overwrite an edit made by an admin in the meantime
+
+    async fn update_attributes(
+        &self,
+        id: &str,
+        set: Vec<(&str, AttributeValue)>,
+        remove: &[&str],
+    ) -> Result<(), Error> {
+        let mut request = self
+            .client
+            .update_item()
             .table_name(&self.table_name)
-            .set_item(Some(item))
+            .key("Id", AttributeValue::S(id.to_string()))
+            .condition_expression("attribute_exists(Id)");
+
+        let mut clauses = vec![];
+
+        if !set.is_empty() {
+            let mut assignments = vec![];
+
+            for (i, (name, value)) in set.into_iter().enumerate() {
+                assignments.push(format!("#set{i} = :set{i}"));
+                request = request
+                    .expression_attribute_names(format!("#set{i}"), name)
+                    .expression_attribute_values(format!(":set{i}"), value);
+            }
+
+            clauses.push(format!("SET {}", assignments.join(", ")));
+        }
+
+        if !remove.is_empty() {
+            let mut names = vec![];
+
+            for (i, name) in remove.iter().enumerate() {
+                names.push(format!("#remove{i}"));
+                request = request.expression_attribute_names(format!("#remove{i}"), *name);
+            }
+
+            clauses.push(format!("REMOVE {}", names.join(", ")));
+        }
+
+        request
+            .update_expression(clauses.join(" "))
             .send()
             .await
             .map_err(errors::map_aws_error)?;
@@ -7,12 +7,46 @@ pub struct TextPostingRule {
     #[serde(flatten)]
     base: BasePostingRule,
     pub content: TextPostingRuleContent,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_variant_indices: Vec<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct TextPostingRuleContent {
+    #[serde(default)]
     pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
+    #[serde(default)]
+    pub variant_selection: TextPostingRuleVariantSelection,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "Type", rename_all = "PascalCase")]
+pub enum TextPostingRuleVariantSelection {
+    #[default]
+    RoundRobin,
+    Random,
+    RandomWithoutRepeat {
+        #[serde(rename = "LastPosts")]
+        last_posts: usize,
+    },
+}
+
+impl TextPostingRuleContent {
+    // Rules with variants post one of them instead of the text, the index is kept on the post
+    // for edits and on the rule in RecentVariantIndices for the rotation, most recent first
+
+    pub fn text_for_variant(&self, variant_index: Option<usize>) -> Option<&str> {
+        if self.variants.is_empty() {
+            return Some(&self.text);
+        }
+
+        variant_index
+            .and_then(|index| self.variants.get(index))
+            .map(|variant| variant.as_str())
+    }
 }
 
 impl PostingRuleTrait for TextPostingRule {
@@ -23,4 +57,8 @@ impl PostingRuleTrait for TextPostingRule {
     fn set_active(&mut self, active: bool) {
         self.base.is_active = active;
     }
+
+    fn set_completed(&mut self, completed_at: Option<i64>) {
+        self.base.completed_at = completed_at;
+    }
 }
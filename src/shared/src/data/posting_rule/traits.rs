@@ -1,4 +1,9 @@
-use crate::data::posting_rule::BasePostingRule;
+use crate::{
+    data::posting_rule::{
+        BasePostingRule, PostingRuleBlackout, PostingRuleSchedule, PostingRuleSkip,
+    },
+    template::Locale,
+};
 use teloxide::types::{ChatId, MessageId};
 
 pub trait PostingRuleTrait {
@@ -36,10 +41,18 @@ pub trait PostingRuleTrait {
         &self.base().timezone
     }
 
+    fn locale(&self) -> Locale {
+        self.base().locale
+    }
+
     fn should_pin(&self) -> bool {
         self.base().should_pin
     }
 
+    fn unpin_previous(&self) -> bool {
+        self.base().unpin_previous
+    }
+
     fn is_active(&self) -> bool {
         self.base().is_active
     }
@@ -48,5 +61,53 @@ pub trait PostingRuleTrait {
         self.base().ttl_hours
     }
 
+    fn delete_on_expire(&self) -> bool {
+        self.base().delete_on_expire
+    }
+
+    fn propagate_edits(&self) -> bool {
+        self.base().propagate_edits
+    }
+
+    fn schedules(&self) -> &[PostingRuleSchedule] {
+        &self.base().schedules
+    }
+
+    fn run_at(&self) -> Option<&str> {
+        self.base().run_at.as_deref()
+    }
+
+    fn is_one_shot(&self) -> bool {
+        self.base().run_at.is_some()
+    }
+
+    fn is_completed(&self) -> bool {
+        self.base().completed_at.is_some()
+    }
+
+    fn start_date(&self) -> Option<i64> {
+        self.base().start_date
+    }
+
+    fn end_date(&self) -> Option<i64> {
+        self.base().end_date
+    }
+
+    fn is_within_dates(&self, timestamp: i64) -> bool {
+        self.start_date()
+            .is_none_or(|start_date| timestamp >= start_date)
+            && self.end_date().is_none_or(|end_date| timestamp <= end_date)
+    }
+
+    fn blackout(&self) -> Option<&PostingRuleBlackout> {
+        self.base().blackout.as_ref()
+    }
+
+    fn last_skip(&self) -> Option<&PostingRuleSkip> {
+        self.base().last_skip.as_ref()
+    }
+
     fn set_active(&mut self, active: bool);
+
+    fn set_completed(&mut self, completed_at: Option<i64>);
 }
@@ -1,4 +1,9 @@
-use crate::data::BasePostingRule;
+use crate::{cron::CronExpression, data::BasePostingRule, date};
+
+// Telegram only lets bots delete group messages younger than 48 hours, and DynamoDB TTL removes
+// expired items with a delay, so a longer TTL leaves the message in the chat
+
+const MAX_DELETE_ON_EXPIRE_TTL_HOURS: i64 = 46;
 
 pub struct BasePostingRuleValidator;
 
@@ -32,29 +37,121 @@ impl BasePostingRuleValidator {
             }
         }
 
-        Self::validate_schedule(&posting_rule.schedule, issues);
+        Self::validate_schedules(posting_rule, issues);
 
-        if posting_rule.timezone.trim().is_empty() {
-            issues.push("Timezone is empty".to_string());
-        }
+        Self::validate_timezone("Timezone", &posting_rule.timezone, issues);
 
         if let Some(ttl_hours) = posting_rule.ttl_hours {
             if ttl_hours <= 0 {
                 issues.push("TtlHours is invalid".to_string());
             }
         }
+
+        Self::validate_dates(posting_rule, issues);
+
+        if let Some(blackout) = &posting_rule.blackout {
+            if blackout.calendar_id.trim().is_empty() {
+                issues.push("Blackout CalendarId is empty".to_string());
+            }
+        }
+
+        if posting_rule.unpin_previous && !posting_rule.should_pin {
+            issues.push("UnpinPrevious requires ShouldPin".to_string());
+        }
+
+        if posting_rule.delete_on_expire {
+            match posting_rule.ttl_hours {
+                Some(ttl_hours) if ttl_hours > MAX_DELETE_ON_EXPIRE_TTL_HOURS => {
+                    issues.push(format!(
+                        "DeleteOnExpire requires TtlHours of at most {}, Telegram cannot delete older messages",
+                        MAX_DELETE_ON_EXPIRE_TTL_HOURS
+                    ));
+                }
+                Some(_) => (),
+                None => issues.push("DeleteOnExpire requires TtlHours".to_string()),
+            }
+        }
     }
 
-    fn validate_schedule(schedule: &str, issues: &mut Vec<String>) {
-        if schedule.trim().is_empty() {
-            issues.push("Schedule is empty".to_string());
+    fn validate_schedules(posting_rule: &BasePostingRule, issues: &mut Vec<String>) {
+        let has_schedule = !posting_rule.schedule.trim().is_empty();
+        let has_schedules = !posting_rule.schedules.is_empty();
+
+        if let Some(run_at) = &posting_rule.run_at {
+            if has_schedule || has_schedules {
+                issues.push("Schedule and RunAt cannot be used together".to_string());
+            }
+
+            if date::parse_run_at(run_at).is_none() {
+                issues.push(format!("RunAt must be in {} format", date::RUN_AT_FORMAT));
+            }
+
+            return;
+        }
+
+        if !has_schedules {
+            Self::validate_schedule("Schedule", &posting_rule.schedule, issues);
+            return;
+        }
+
+        if has_schedule {
+            issues.push("Schedule and Schedules cannot be used together".to_string());
+        }
+
+        for (i, schedule) in posting_rule.schedules.iter().enumerate() {
+            Self::validate_schedule(&format!("Schedules {}", i + 1), &schedule.schedule, issues);
+
+            if let Some(timezone) = &schedule.timezone {
+                Self::validate_timezone(&format!("Schedules {} Timezone", i + 1), timezone, issues);
+            }
+        }
+    }
+
+    fn validate_dates(posting_rule: &BasePostingRule, issues: &mut Vec<String>) {
+        if let Some(start_date) = posting_rule.start_date {
+            if start_date <= 0 {
+                issues.push("StartDate is invalid".to_string());
+            }
+        }
+
+        if let Some(end_date) = posting_rule.end_date {
+            if end_date <= 0 {
+                issues.push("EndDate is invalid".to_string());
+            }
+        }
+
+        if let (Some(start_date), Some(end_date)) = (posting_rule.start_date, posting_rule.end_date)
+        {
+            if start_date >= end_date {
+                issues.push("StartDate must be before EndDate".to_string());
+            }
+        }
+    }
+
+    fn validate_timezone(field: &str, timezone: &str, issues: &mut Vec<String>) {
+        if timezone.trim().is_empty() {
+            issues.push(format!("{} is empty", field));
             return;
         }
 
-        let parts = schedule.split_whitespace().collect::<Vec<_>>();
+        if date::parse_timezone(timezone).is_err() {
+            issues.push(format!(
+                "{} {} is not a valid IANA timezone",
+                field, timezone
+            ));
+        }
+    }
+
+    pub fn validate_schedule(field: &str, schedule: &str, issues: &mut Vec<String>) {
+        if schedule.trim().is_empty() {
+            issues.push(format!("{} is empty", field));
+            return;
+        }
 
-        if parts.len() != 6 {
-            issues.push("Schedule must have 6 parts".to_string());
+        if let Err(errors) = CronExpression::parse(schedule) {
+            for error in errors {
+                issues.push(format!("{} {}", field, error));
+            }
         }
     }
 }
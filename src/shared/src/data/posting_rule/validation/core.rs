@@ -1,7 +1,10 @@
 use crate::data::{PostingRule, PostingRuleTrait};
+use std::collections::HashMap;
 
 use crate::data::posting_rule::validation::{
-    base::BasePostingRuleValidator, PollPostingRuleValidator, TextPostingRuleValidator,
+    base::BasePostingRuleValidator, DocumentPostingRuleValidator, MediaGroupPostingRuleValidator,
+    PhotoPostingRuleValidator, PollPostingRuleValidator, QueuePostingRuleValidator,
+    TemplatePostingRuleValidator, TextPostingRuleValidator, VideoPostingRuleValidator,
 };
 
 pub struct PostingRuleValidator;
@@ -19,6 +22,99 @@ impl PostingRuleValidator {
             PostingRule::Poll(poll_rule) => {
                 PollPostingRuleValidator::validate(poll_rule, &mut issues);
             }
+            PostingRule::Photo(photo_rule) => {
+                PhotoPostingRuleValidator::validate(photo_rule, &mut issues);
+            }
+            PostingRule::Video(video_rule) => {
+                VideoPostingRuleValidator::validate(video_rule, &mut issues);
+            }
+            PostingRule::Document(document_rule) => {
+                DocumentPostingRuleValidator::validate(document_rule, &mut issues);
+            }
+            PostingRule::MediaGroup(media_group_rule) => {
+                MediaGroupPostingRuleValidator::validate(media_group_rule, &mut issues);
+            }
+            PostingRule::Queue(queue_rule) => {
+                QueuePostingRuleValidator::validate(queue_rule, &mut issues);
+            }
+        }
+
+        issues
+    }
+
+    // Custom variables live in their own table, so they are checked separately against the values
+    // defined for the rule's chat. Queue items are checked when they are enqueued
+
+    pub fn validate_variables(
+        posting_rule: &PostingRule,
+        variables: &HashMap<String, String>,
+    ) -> Vec<String> {
+        let mut texts: Vec<(String, &str)> = vec![];
+
+        match posting_rule {
+            PostingRule::Text(text_rule) => {
+                let content = &text_rule.content;
+
+                if content.variants.is_empty() {
+                    texts.push(("Text".to_string(), &content.text));
+                }
+
+                for (i, variant) in content.variants.iter().enumerate() {
+                    texts.push((format!("Variants {}", i + 1), variant));
+                }
+            }
+            PostingRule::Poll(poll_rule) => {
+                texts.push(("Question".to_string(), &poll_rule.content.question));
+
+                if let Some(header) = poll_rule
+                    .results_summary
+                    .as_ref()
+                    .and_then(|results_summary| results_summary.header.as_deref())
+                {
+                    texts.push(("ResultsSummary Header".to_string(), header));
+                }
+            }
+            PostingRule::Photo(photo_rule) => {
+                texts.extend(
+                    photo_rule
+                        .content
+                        .caption
+                        .as_deref()
+                        .map(|caption| ("Caption".to_string(), caption)),
+                );
+            }
+            PostingRule::Video(video_rule) => {
+                texts.extend(
+                    video_rule
+                        .content
+                        .caption
+                        .as_deref()
+                        .map(|caption| ("Caption".to_string(), caption)),
+                );
+            }
+            PostingRule::Document(document_rule) => {
+                texts.extend(
+                    document_rule
+                        .content
+                        .caption
+                        .as_deref()
+                        .map(|caption| ("Caption".to_string(), caption)),
+                );
+            }
+            PostingRule::MediaGroup(media_group_rule) => {
+                for (i, item) in media_group_rule.content.items.iter().enumerate() {
+                    if let Some(caption) = item.caption.as_deref() {
+                        texts.push((format!("Item {}: Caption", i + 1), caption));
+                    }
+                }
+            }
+            PostingRule::Queue(_) => {}
+        }
+
+        let mut issues = vec![];
+
+        for (field, text) in texts {
+            TemplatePostingRuleValidator::validate_variables(&field, text, variables, &mut issues);
         }
 
         issues
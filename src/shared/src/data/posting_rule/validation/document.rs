@@ -0,0 +1,13 @@
+use crate::data::{posting_rule::validation::MediaPostingRuleValidator, DocumentPostingRule};
+
+pub struct DocumentPostingRuleValidator;
+
+impl DocumentPostingRuleValidator {
+    pub fn validate(posting_rule: &DocumentPostingRule, issues: &mut Vec<String>) {
+        MediaPostingRuleValidator::validate(
+            &posting_rule.content.media,
+            posting_rule.content.caption.as_deref(),
+            issues,
+        );
+    }
+}
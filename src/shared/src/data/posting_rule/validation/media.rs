@@ -0,0 +1,30 @@
+use crate::data::{posting_rule::validation::TemplatePostingRuleValidator, PostingRuleMedia};
+
+pub struct MediaPostingRuleValidator;
+
+impl MediaPostingRuleValidator {
+    pub fn validate(media: &PostingRuleMedia, caption: Option<&str>, issues: &mut Vec<String>) {
+        match media {
+            PostingRuleMedia::FileId { file_id } => {
+                if file_id.trim().is_empty() {
+                    issues.push("Media FileId is empty".to_string());
+                }
+            }
+            PostingRuleMedia::Url { url } => {
+                if url.trim().is_empty() {
+                    issues.push("Media Url is empty".to_string());
+                } else if !url.starts_with("https://") && !url.starts_with("http://") {
+                    issues.push("Media Url must start with http:// or https://".to_string());
+                }
+            }
+        }
+
+        if let Some(caption) = caption {
+            if caption.trim().is_empty() {
+                issues.push("Caption is empty".to_string());
+            }
+
+            TemplatePostingRuleValidator::validate("Caption", caption, issues);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::data::{
+    posting_rule::validation::MediaPostingRuleValidator, MediaGroupPostingRule,
+    MediaGroupPostingRuleItemType,
+};
+
+const MIN_ITEMS: usize = 2;
+const MAX_ITEMS: usize = 10;
+
+pub struct MediaGroupPostingRuleValidator;
+
+impl MediaGroupPostingRuleValidator {
+    pub fn validate(posting_rule: &MediaGroupPostingRule, issues: &mut Vec<String>) {
+        let items = &posting_rule.content.items;
+
+        if items.len() < MIN_ITEMS || items.len() > MAX_ITEMS {
+            issues.push(format!(
+                "Items must contain from {} to {} entries",
+                MIN_ITEMS, MAX_ITEMS
+            ));
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            let mut item_issues = vec![];
+
+            MediaPostingRuleValidator::validate(
+                &item.media,
+                item.caption.as_deref(),
+                &mut item_issues,
+            );
+
+            issues.extend(
+                item_issues
+                    .into_iter()
+                    .map(|issue| format!("Item {}: {}", i + 1, issue)),
+            );
+        }
+
+        let has_documents = items
+            .iter()
+            .any(|item| item.media_type == MediaGroupPostingRuleItemType::Document);
+
+        let has_other_media = items
+            .iter()
+            .any(|item| item.media_type != MediaGroupPostingRuleItemType::Document);
+
+        if has_documents && has_other_media {
+            issues.push("Documents cannot be mixed with photos and videos".to_string());
+        }
+    }
+}
@@ -1,8 +1,22 @@
 mod base;
 mod core;
+mod document;
+mod media;
+mod media_group;
+mod photo;
 mod poll;
+mod queue;
+mod template;
 mod text;
+mod video;
 
 pub use core::PostingRuleValidator;
+pub use document::DocumentPostingRuleValidator;
+pub use media::MediaPostingRuleValidator;
+pub use media_group::MediaGroupPostingRuleValidator;
+pub use photo::PhotoPostingRuleValidator;
 pub use poll::PollPostingRuleValidator;
+pub use queue::QueuePostingRuleValidator;
+pub use template::TemplatePostingRuleValidator;
 pub use text::TextPostingRuleValidator;
+pub use video::VideoPostingRuleValidator;
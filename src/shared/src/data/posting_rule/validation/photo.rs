@@ -0,0 +1,13 @@
+use crate::data::{posting_rule::validation::MediaPostingRuleValidator, PhotoPostingRule};
+
+pub struct PhotoPostingRuleValidator;
+
+impl PhotoPostingRuleValidator {
+    pub fn validate(posting_rule: &PhotoPostingRule, issues: &mut Vec<String>) {
+        MediaPostingRuleValidator::validate(
+            &posting_rule.content.media,
+            posting_rule.content.caption.as_deref(),
+            issues,
+        );
+    }
+}
@@ -1,4 +1,11 @@
-use crate::data::PollPostingRule;
+use crate::data::{
+    posting_rule::validation::{base::BasePostingRuleValidator, TemplatePostingRuleValidator},
+    PollPostingRule, PollPostingRulePollType, PollPostingRuleResultsSummaryTarget,
+};
+
+const MIN_OPEN_PERIOD: u16 = 5;
+const MAX_OPEN_PERIOD: u16 = 600;
+const MAX_EXPLANATION_LENGTH: usize = 200;
 
 pub struct PollPostingRuleValidator;
 
@@ -8,6 +15,8 @@ impl PollPostingRuleValidator {
             issues.push("Question is empty".to_string());
         }
 
+        TemplatePostingRuleValidator::validate("Question", &posting_rule.content.question, issues);
+
         if posting_rule.content.options.is_empty() {
             issues.push("Options are empty".to_string());
         } else {
@@ -17,5 +26,105 @@ impl PollPostingRuleValidator {
                 }
             }
         }
+
+        Self::validate_poll_type(posting_rule, issues);
+        Self::validate_closing(posting_rule, issues);
+        Self::validate_results_summary(posting_rule, issues);
+
+        if posting_rule.content.is_anonymous && posting_rule.action_log.is_some() {
+            issues.push("ActionLog requires a non-anonymous poll".to_string());
+        }
+    }
+
+    fn validate_poll_type(posting_rule: &PollPostingRule, issues: &mut Vec<String>) {
+        let (correct_option_id, explanation) = match &posting_rule.content.poll_type {
+            Some(PollPostingRulePollType::Quiz {
+                correct_option_id,
+                explanation,
+            }) => (correct_option_id, explanation),
+            _ => return,
+        };
+
+        if posting_rule.content.option_sourcing.is_some() {
+            issues.push("Quiz polls cannot use OptionSourcing".to_string());
+        }
+
+        if posting_rule.content.allows_multiple_answers {
+            issues.push("Quiz polls cannot allow multiple answers".to_string());
+        }
+
+        if *correct_option_id as usize >= posting_rule.content.options.len() {
+            issues.push("CorrectOptionId is out of range".to_string());
+        }
+
+        if let Some(explanation) = explanation {
+            if explanation.trim().is_empty() {
+                issues.push("Explanation is empty".to_string());
+            } else if explanation.chars().count() > MAX_EXPLANATION_LENGTH {
+                issues.push(format!(
+                    "Explanation must not exceed {} characters",
+                    MAX_EXPLANATION_LENGTH
+                ));
+            }
+        }
+    }
+
+    fn validate_results_summary(posting_rule: &PollPostingRule, issues: &mut Vec<String>) {
+        let results_summary = match &posting_rule.results_summary {
+            Some(results_summary) => results_summary,
+            None => return,
+        };
+
+        if posting_rule.close_schedule.is_none() && posting_rule.content.close_date.is_none() {
+            issues.push("ResultsSummary requires CloseSchedule or CloseDate".to_string());
+        }
+
+        if let PollPostingRuleResultsSummaryTarget::ActionLogChat = results_summary.target {
+            if posting_rule.action_log.is_none() {
+                issues.push("ResultsSummary ActionLogChat target requires ActionLog".to_string());
+            }
+        }
+
+        if let Some(header) = &results_summary.header {
+            if header.trim().is_empty() {
+                issues.push("ResultsSummary Header is empty".to_string());
+            }
+
+            TemplatePostingRuleValidator::validate("ResultsSummary Header", header, issues);
+        }
+    }
+
+    fn validate_closing(posting_rule: &PollPostingRule, issues: &mut Vec<String>) {
+        if let Some(open_period) = posting_rule.content.open_period {
+            if !(MIN_OPEN_PERIOD..=MAX_OPEN_PERIOD).contains(&open_period) {
+                issues.push(format!(
+                    "OpenPeriod must be between {} and {} seconds",
+                    MIN_OPEN_PERIOD, MAX_OPEN_PERIOD
+                ));
+            }
+        }
+
+        if let Some(close_date) = posting_rule.content.close_date {
+            if close_date <= 0 {
+                issues.push("CloseDate is invalid".to_string());
+            }
+
+            if posting_rule.content.open_period.is_some() {
+                issues.push("CloseDate cannot be used together with OpenPeriod".to_string());
+            }
+        }
+
+        if let Some(close_schedule) = &posting_rule.close_schedule {
+            BasePostingRuleValidator::validate_schedule("CloseSchedule", close_schedule, issues);
+
+            if posting_rule.content.open_period.is_some()
+                || posting_rule.content.close_date.is_some()
+            {
+                issues.push(
+                    "CloseSchedule cannot be used together with OpenPeriod or CloseDate"
+                        .to_string(),
+                );
+            }
+        }
     }
 }
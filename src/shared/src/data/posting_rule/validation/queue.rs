@@ -0,0 +1,19 @@
+use crate::data::{PostingRuleTrait, QueuePostingRule, QueuePostingRuleEmptyQueueBehavior};
+
+pub struct QueuePostingRuleValidator;
+
+impl QueuePostingRuleValidator {
+    pub fn validate(posting_rule: &QueuePostingRule, issues: &mut Vec<String>) {
+        if let QueuePostingRuleEmptyQueueBehavior::NotifyAdmins { chat_id } =
+            posting_rule.content.empty_queue_behavior
+        {
+            if chat_id == 0 {
+                issues.push("EmptyQueueBehavior ChatId is empty".to_string());
+            }
+        }
+
+        if posting_rule.propagate_edits() {
+            issues.push("PropagateEdits is not supported for queue rules".to_string());
+        }
+    }
+}
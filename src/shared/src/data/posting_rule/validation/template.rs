@@ -0,0 +1,71 @@
+use crate::template::Template;
+use std::collections::HashMap;
+
+pub struct TemplatePostingRuleValidator;
+
+impl TemplatePostingRuleValidator {
+    pub fn validate(field: &str, text: &str, issues: &mut Vec<String>) {
+        if let Err(errors) = Template::parse(text) {
+            for error in errors {
+                issues.push(format!("{} {}", field, error));
+            }
+        }
+    }
+
+    // Texts that fail to parse are already reported by validate
+
+    pub fn validate_variables(
+        field: &str,
+        text: &str,
+        variables: &HashMap<String, String>,
+        issues: &mut Vec<String>,
+    ) {
+        if let Ok(template) = Template::parse(text) {
+            for name in template.custom_variables() {
+                if !variables.contains_key(name) {
+                    issues.push(format!(
+                        "{} uses undefined variable {{vars.{}}}",
+                        field, name
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(text: &str, variables: &[(&str, &str)]) -> Vec<String> {
+        let variables = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let mut issues = vec![];
+
+        TemplatePostingRuleValidator::validate("Text", text, &mut issues);
+        TemplatePostingRuleValidator::validate_variables("Text", text, &variables, &mut issues);
+        issues
+    }
+
+    #[test]
+    fn rejects_unknown_variables() {
+        assert_eq!(
+            issues("Встречаемся в {venue}", &[]),
+            vec!["Text unknown variable {venue}"]
+        );
+    }
+
+    #[test]
+    fn rejects_undefined_custom_variables() {
+        assert_eq!(
+            issues(
+                "{vars.place} {if day > 1}{vars.count}{vars.place}{end}",
+                &[("place", "зал 2")]
+            ),
+            vec!["Text uses undefined variable {vars.count}"]
+        );
+        assert!(issues("{vars.place}", &[("place", "зал 2")]).is_empty());
+    }
+}
@@ -1,11 +1,43 @@
-use crate::data::TextPostingRule;
+use crate::data::{
+    posting_rule::validation::TemplatePostingRuleValidator, TextPostingRule,
+    TextPostingRuleVariantSelection,
+};
 
 pub struct TextPostingRuleValidator;
 
 impl TextPostingRuleValidator {
     pub fn validate(posting_rule: &TextPostingRule, issues: &mut Vec<String>) {
-        if posting_rule.content.text.trim().is_empty() {
-            issues.push("Text is empty".to_string());
+        let content = &posting_rule.content;
+
+        if content.variants.is_empty() {
+            if content.text.trim().is_empty() {
+                issues.push("Text is empty".to_string());
+            }
+
+            TemplatePostingRuleValidator::validate("Text", &content.text, issues);
+            return;
+        }
+
+        if !content.text.trim().is_empty() {
+            issues.push("Text and Variants cannot be used together".to_string());
+        }
+
+        for (i, variant) in content.variants.iter().enumerate() {
+            let field = format!("Variants {}", i + 1);
+
+            if variant.trim().is_empty() {
+                issues.push(format!("{} is empty", field));
+            }
+
+            TemplatePostingRuleValidator::validate(&field, variant, issues);
+        }
+
+        if let TextPostingRuleVariantSelection::RandomWithoutRepeat { last_posts } =
+            content.variant_selection
+        {
+            if last_posts == 0 {
+                issues.push("VariantSelection LastPosts is invalid".to_string());
+            }
         }
     }
 }
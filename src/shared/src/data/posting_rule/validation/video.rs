@@ -0,0 +1,13 @@
+use crate::data::{posting_rule::validation::MediaPostingRuleValidator, VideoPostingRule};
+
+pub struct VideoPostingRuleValidator;
+
+impl VideoPostingRuleValidator {
+    pub fn validate(posting_rule: &VideoPostingRule, issues: &mut Vec<String>) {
+        MediaPostingRuleValidator::validate(
+            &posting_rule.content.media,
+            posting_rule.content.caption.as_deref(),
+            issues,
+        );
+    }
+}
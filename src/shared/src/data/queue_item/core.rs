@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+// Items of a queue posting rule are sorted by position, the first one is posted on the next run
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QueueItem {
+    pub posting_rule_id: String,
+    pub position: i64,
+    pub text: String,
+    pub created_at: i64,
+}
+
+impl QueueItem {
+    pub fn new(posting_rule_id: &str, position: i64, text: &str) -> Self {
+        Self {
+            posting_rule_id: posting_rule_id.to_string(),
+            position,
+            text: text.to_string(),
+            created_at: chrono::Utc::now().timestamp(),
+        }
+    }
+}
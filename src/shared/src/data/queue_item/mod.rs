@@ -0,0 +1,5 @@
+mod core;
+mod repository;
+
+pub use core::QueueItem;
+pub use repository::QueueItemRepository;
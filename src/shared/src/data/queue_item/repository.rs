@@ -0,0 +1,218 @@
+use crate::{aws::errors, data::queue_item::QueueItem, env};
+use anyhow::{anyhow, Error};
+use aws_sdk_dynamodb::{
+    operation::transact_write_items::TransactWriteItemsError,
+    types::{AttributeValue, Put, ReturnValue, TransactWriteItem},
+    Client,
+};
+use tracing::warn;
+
+const APPEND_ATTEMPTS: usize = 5;
+
+pub struct QueueItemRepository {
+    client: Client,
+    table_name: String,
+}
+
+impl QueueItemRepository {
+    // DynamoDB limits a transaction to 100 items
+
+    pub const MAX_APPEND_ITEMS: usize = 100;
+
+    pub async fn new(dynamodb_client: Client) -> Result<Self, Error> {
+        let table_name = env::get_env_var("QUEUE_ITEMS_TABLE")?;
+
+        Ok(Self {
+            client: dynamodb_client,
+            table_name,
+        })
+    }
+
+    pub async fn get_by_posting_rule(
+        &self,
+        posting_rule_id: &str,
+    ) -> Result<Vec<QueueItem>, Error> {
+        let mut items = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression("PostingRuleId = :rule_id")
+                .expression_attribute_values(
+                    ":rule_id",
+                    AttributeValue::S(posting_rule_id.to_string()),
+                )
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(errors::map_aws_error)?;
+
+            for item in result.items.unwrap_or_default() {
+                items.push(serde_dynamo::from_item(item)?);
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    // Removes the first item and returns it, the conditional delete makes sure that overlapping runs
+    // never get the same item, the run that loses the race takes the next one instead
+
+    pub async fn claim_first(&self, posting_rule_id: &str) -> Result<Option<QueueItem>, Error> {
+        loop {
+            let item = match self.get_edge(posting_rule_id, true).await? {
+                Some(item) => item,
+                None => return Ok(None),
+            };
+
+            let result = self
+                .client
+                .delete_item()
+                .table_name(&self.table_name)
+                .key(
+                    "PostingRuleId",
+                    AttributeValue::S(posting_rule_id.to_string()),
+                )
+                .key("Position", AttributeValue::N(item.position.to_string()))
+                .condition_expression("attribute_exists(#position)")
+                .expression_attribute_names("#position", "Position")
+                .return_values(ReturnValue::AllOld)
+                .send()
+                .await;
+
+            match result {
+                Ok(output) => {
+                    if let Some(attributes) = output.attributes {
+                        return Ok(Some(serde_dynamo::from_item(attributes)?));
+                    }
+                }
+                Err(err) => {
+                    let is_claimed = err.as_service_error().is_some_and(|service_error| {
+                        service_error.is_conditional_check_failed_exception()
+                    });
+
+                    if !is_claimed {
+                        return Err(errors::map_aws_error(err));
+                    }
+                }
+            }
+        }
+    }
+
+    // Puts a claimed item back to its position when it could not be posted
+
+    pub async fn restore(&self, item: &QueueItem) -> Result<(), Error> {
+        let item = serde_dynamo::to_item(item)?;
+
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(#position)")
+            .expression_attribute_names("#position", "Position")
+            .send()
+            .await
+            .map_err(errors::map_aws_error)?;
+
+        Ok(())
+    }
+
+    // New items are placed after the last one and written in a single transaction, so a concurrent append
+    // that takes the same positions cancels it as a whole and it is retried after the new last item
+
+    pub async fn append(&self, posting_rule_id: &str, texts: &[&str]) -> Result<(), Error> {
+        if texts.len() > Self::MAX_APPEND_ITEMS {
+            return Err(anyhow!(
+                "Cannot append more than {} queue items at once, posting_rule_id: {}",
+                Self::MAX_APPEND_ITEMS,
+                posting_rule_id
+            ));
+        }
+
+        for _ in 0..APPEND_ATTEMPTS {
+            let last_position = self
+                .get_edge(posting_rule_id, false)
+                .await?
+                .map_or(0, |item| item.position);
+
+            let mut transact_items = vec![];
+
+            for (i, text) in texts.iter().enumerate() {
+                let position = last_position + i as i64 + 1;
+                let item = serde_dynamo::to_item(QueueItem::new(posting_rule_id, position, text))?;
+
+                let put = Put::builder()
+                    .table_name(&self.table_name)
+                    .set_item(Some(item))
+                    .condition_expression("attribute_not_exists(#position)")
+                    .expression_attribute_names("#position", "Position")
+                    .build()?;
+
+                transact_items.push(TransactWriteItem::builder().put(put).build());
+            }
+
+            let result = self
+                .client
+                .transact_write_items()
+                .set_transact_items(Some(transact_items))
+                .send()
+                .await;
+
+            let err = match result {
+                Ok(_) => return Ok(()),
+                Err(err) => err,
+            };
+
+            let is_conflict = matches!(
+                err.as_service_error(),
+                Some(TransactWriteItemsError::TransactionCanceledException(_))
+            );
+
+            if !is_conflict {
+                return Err(errors::map_aws_error(err));
+            }
+
+            warn!(
+                posting_rule_id,
+                "Queue items were appended concurrently, retrying"
+            );
+        }
+
+        Err(anyhow!(
+            "Failed to append queue items after {} attempts due to concurrent appends, posting_rule_id: {}",
+            APPEND_ATTEMPTS,
+            posting_rule_id
+        ))
+    }
+
+    async fn get_edge(
+        &self,
+        posting_rule_id: &str,
+        first: bool,
+    ) -> Result<Option<QueueItem>, Error> {
+        let result = self
+            .client
+            .query()
+            .table_name(&self.table_name)
+            .key_condition_expression("PostingRuleId = :rule_id")
+            .expression_attribute_values(":rule_id", AttributeValue::S(posting_rule_id.to_string()))
+            .scan_index_forward(first)
+            .limit(1)
+            .send()
+            .await
+            .map_err(errors::map_aws_error)?;
+
+        match result.items.unwrap_or_default().into_iter().next() {
+            Some(item) => Ok(Some(serde_dynamo::from_item(item)?)),
+            None => Ok(None),
+        }
+    }
+}
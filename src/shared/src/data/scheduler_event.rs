@@ -1,6 +1,36 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+// EventBridge Scheduler replaces this placeholder in the target input with the scheduled time
+// of the invocation in ISO 8601 format
+
+pub const SCHEDULED_TIME_PLACEHOLDER: &str = "<aws.scheduler.scheduled-time>";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SchedulerEvent {
     pub posting_rule_id: String,
+    #[serde(default)]
+    pub action: SchedulerEventAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduled_time: Option<String>,
+}
+
+impl SchedulerEvent {
+    // Direct invocations and old schedules carry no scheduled time, they are handled as if fired now
+
+    pub fn scheduled_at(&self) -> DateTime<Utc> {
+        self.scheduled_time
+            .as_deref()
+            .and_then(|scheduled_time| DateTime::parse_from_rfc3339(scheduled_time).ok())
+            .map(|scheduled_time| scheduled_time.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchedulerEventAction {
+    #[default]
+    Post,
+    ClosePoll,
+    EditLatestPost,
 }
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Variables are defined per bot and can be overridden for a single chat,
+// chat-level ones are stored with the chat id as a prefix of the sort key
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateVariable {
+    pub bot_id: String,
+    pub id: String,
+    pub name: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
+
+impl TemplateVariable {
+    pub fn new(bot_id: &str, chat_id: Option<i64>, name: &str, value: &str) -> Self {
+        Self {
+            bot_id: bot_id.to_string(),
+            id: Self::build_id(chat_id, name),
+            name: name.to_string(),
+            value: value.to_string(),
+            chat_id,
+        }
+    }
+
+    pub fn build_id(chat_id: Option<i64>, name: &str) -> String {
+        match chat_id {
+            Some(chat_id) => format!("{}:{}", chat_id, name),
+            None => name.to_string(),
+        }
+    }
+
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    // Chat-level values take precedence over the bot-level ones with the same name
+
+    pub fn values_for_chat(
+        variables: &[TemplateVariable],
+        chat_id: i64,
+    ) -> HashMap<String, String> {
+        let mut values = HashMap::new();
+
+        for variable in variables
+            .iter()
+            .filter(|variable| variable.chat_id.is_none())
+        {
+            values.insert(variable.name.clone(), variable.value.clone());
+        }
+
+        for variable in variables
+            .iter()
+            .filter(|variable| variable.chat_id == Some(chat_id))
+        {
+            values.insert(variable.name.clone(), variable.value.clone());
+        }
+
+        values
+    }
+}
@@ -0,0 +1,5 @@
+mod core;
+mod repository;
+
+pub use core::TemplateVariable;
+pub use repository::TemplateVariableRepository;
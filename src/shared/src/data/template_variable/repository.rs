@@ -0,0 +1,82 @@
+use crate::{aws::errors, data::template_variable::TemplateVariable, env};
+use anyhow::Error;
+use aws_sdk_dynamodb::{
+    types::{AttributeValue, ReturnValue},
+    Client,
+};
+
+pub struct TemplateVariableRepository {
+    client: Client,
+    table_name: String,
+}
+
+impl TemplateVariableRepository {
+    pub async fn new(dynamodb_client: Client) -> Result<Self, Error> {
+        let table_name = env::get_env_var("TEMPLATE_VARIABLES_TABLE")?;
+
+        Ok(Self {
+            client: dynamodb_client,
+            table_name,
+        })
+    }
+
+    pub async fn get_by_bot(&self, bot_id: &str) -> Result<Vec<TemplateVariable>, Error> {
+        let mut variables = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression("BotId = :bot_id")
+                .expression_attribute_values(":bot_id", AttributeValue::S(bot_id.to_string()))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(errors::map_aws_error)?;
+
+            for item in result.items.unwrap_or_default() {
+                variables.push(serde_dynamo::from_item(item)?);
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(variables)
+    }
+
+    pub async fn put(&self, item: &TemplateVariable) -> Result<(), Error> {
+        let item = serde_dynamo::to_item(item)?;
+
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map_err(errors::map_aws_error)?;
+
+        Ok(())
+    }
+
+    // Returns false if there was no variable with the given id
+
+    pub async fn delete(&self, bot_id: &str, id: &str) -> Result<bool, Error> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key("BotId", AttributeValue::S(bot_id.to_string()))
+            .key("Id", AttributeValue::S(id.to_string()))
+            .return_values(ReturnValue::AllOld)
+            .send()
+            .await
+            .map_err(errors::map_aws_error)?;
+
+        Ok(result.attributes.is_some())
+    }
+}
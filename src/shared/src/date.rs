@@ -1,4 +1,8 @@
-use chrono::{Duration, Local};
+use anyhow::{anyhow, Error};
+use chrono::{Duration, Local, NaiveDateTime};
+use chrono_tz::Tz;
+
+pub const RUN_AT_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
 
 pub fn calculate_expires_at(hours_from_now: i64) -> i64 {
     let now = Local::now();
@@ -6,3 +10,18 @@ pub fn calculate_expires_at(hours_from_now: i64) -> i64 {
 
     expiry_time.timestamp()
 }
+
+pub fn parse_run_at(run_at: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(run_at.trim(), RUN_AT_FORMAT).ok()
+}
+
+// Timezones must be IANA names, EventBridge Scheduler accepts the same set
+
+pub fn parse_timezone(timezone: &str) -> Result<Tz, Error> {
+    timezone.parse::<Tz>().map_err(|_| {
+        anyhow!(
+            "Invalid timezone {}, expected an IANA name such as Europe/Moscow",
+            timezone
+        )
+    })
+}
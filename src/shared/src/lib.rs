@@ -1,4 +1,6 @@
 pub mod aws;
+pub mod cron;
 pub mod data;
 pub mod date;
 pub mod env;
+pub mod template;
@@ -0,0 +1,36 @@
+use crate::{date, template::Locale};
+use anyhow::Error;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+
+// Variables are evaluated at the scheduled fire time in the timezone of the posting rule,
+// not at the moment the Lambda happens to run in UTC
+
+pub struct TemplateContext {
+    pub fire_time: DateTime<Tz>,
+    pub variables: HashMap<String, String>,
+    pub locale: Locale,
+}
+
+impl TemplateContext {
+    pub fn new(fire_time: DateTime<Utc>, timezone: &str) -> Result<Self, Error> {
+        let tz = date::parse_timezone(timezone)?;
+
+        Ok(Self {
+            fire_time: fire_time.with_timezone(&tz),
+            variables: HashMap::new(),
+            locale: Locale::default(),
+        })
+    }
+
+    pub fn with_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
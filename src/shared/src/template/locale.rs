@@ -0,0 +1,319 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub enum Locale {
+    #[default]
+    Russian,
+    English,
+    Ukrainian,
+}
+
+// Grammatical cases are selected with a format, e.g. {month:gen} for "марта" and {weekday:acc} for "в среду",
+// languages without cases use the nominative form for all of them
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthForm {
+    Nominative,
+    Genitive,
+    Prepositional,
+    Short,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekdayForm {
+    Nominative,
+    Accusative,
+    Short,
+}
+
+const RUSSIAN_MONTHS: [[&str; 12]; 4] = [
+    [
+        "январь",
+        "февраль",
+        "март",
+        "апрель",
+        "май",
+        "июнь",
+        "июль",
+        "август",
+        "сентябрь",
+        "октябрь",
+        "ноябрь",
+        "декабрь",
+    ],
+    [
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ],
+    [
+        "январе",
+        "феврале",
+        "марте",
+        "апреле",
+        "мае",
+        "июне",
+        "июле",
+        "августе",
+        "сентябре",
+        "октябре",
+        "ноябре",
+        "декабре",
+    ],
+    [
+        "янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек",
+    ],
+];
+
+const UKRAINIAN_MONTHS: [[&str; 12]; 4] = [
+    [
+        "січень",
+        "лютий",
+        "березень",
+        "квітень",
+        "травень",
+        "червень",
+        "липень",
+        "серпень",
+        "вересень",
+        "жовтень",
+        "листопад",
+        "грудень",
+    ],
+    [
+        "січня",
+        "лютого",
+        "березня",
+        "квітня",
+        "травня",
+        "червня",
+        "липня",
+        "серпня",
+        "вересня",
+        "жовтня",
+        "листопада",
+        "грудня",
+    ],
+    [
+        "січні",
+        "лютому",
+        "березні",
+        "квітні",
+        "травні",
+        "червні",
+        "липні",
+        "серпні",
+        "вересні",
+        "жовтні",
+        "листопаді",
+        "грудні",
+    ],
+    [
+        "січ", "лют", "бер", "кві", "тра", "чер", "лип", "сер", "вер", "жов", "лис", "гру",
+    ],
+];
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const ENGLISH_SHORT_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const RUSSIAN_WEEKDAYS: [[&str; 7]; 3] = [
+    [
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+        "воскресенье",
+    ],
+    [
+        "понедельник",
+        "вторник",
+        "среду",
+        "четверг",
+        "пятницу",
+        "субботу",
+        "воскресенье",
+    ],
+    ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+];
+
+const UKRAINIAN_WEEKDAYS: [[&str; 7]; 3] = [
+    [
+        "понеділок",
+        "вівторок",
+        "середа",
+        "четвер",
+        "п'ятниця",
+        "субота",
+        "неділя",
+    ],
+    [
+        "понеділок",
+        "вівторок",
+        "середу",
+        "четвер",
+        "п'ятницю",
+        "суботу",
+        "неділю",
+    ],
+    ["пн", "вт", "ср", "чт", "пт", "сб", "нд"],
+];
+
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const ENGLISH_SHORT_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+impl MonthForm {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format.trim() {
+            "nom" => Some(MonthForm::Nominative),
+            "gen" => Some(MonthForm::Genitive),
+            "prep" => Some(MonthForm::Prepositional),
+            "short" => Some(MonthForm::Short),
+            _ => None,
+        }
+    }
+}
+
+impl WeekdayForm {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format.trim() {
+            "nom" => Some(WeekdayForm::Nominative),
+            "acc" => Some(WeekdayForm::Accusative),
+            "short" => Some(WeekdayForm::Short),
+            _ => None,
+        }
+    }
+}
+
+impl Locale {
+    // Months are numbered from 1 as in chrono
+
+    pub fn month_name(&self, month: u32, form: MonthForm) -> &'static str {
+        let index = (month as usize - 1) % 12;
+
+        match (self, form) {
+            (Locale::Russian, form) => RUSSIAN_MONTHS[form as usize][index],
+            (Locale::Ukrainian, form) => UKRAINIAN_MONTHS[form as usize][index],
+            (Locale::English, MonthForm::Short) => ENGLISH_SHORT_MONTHS[index],
+            (Locale::English, _) => ENGLISH_MONTHS[index],
+        }
+    }
+
+    pub fn weekday_name(&self, weekday: Weekday, form: WeekdayForm) -> &'static str {
+        let index = weekday.num_days_from_monday() as usize;
+
+        match (self, form) {
+            (Locale::Russian, form) => RUSSIAN_WEEKDAYS[form as usize][index],
+            (Locale::Ukrainian, form) => UKRAINIAN_WEEKDAYS[form as usize][index],
+            (Locale::English, WeekdayForm::Short) => ENGLISH_SHORT_WEEKDAYS[index],
+            (Locale::English, _) => ENGLISH_WEEKDAYS[index],
+        }
+    }
+
+    // Replaces the name specifiers of a date format before it is passed to chrono, which only knows English.
+    // As in glibc, %B is the genitive used with a day ("15 марта") and %OB the standalone nominative ("март 2025")
+
+    pub fn localize_format(&self, format: &str, date: NaiveDate) -> String {
+        let mut output = String::with_capacity(format.len());
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+
+            let name = match chars.peek() {
+                Some('%') => {
+                    chars.next();
+                    output.push_str("%%");
+                    continue;
+                }
+                Some('O') => {
+                    let mut lookahead = chars.clone();
+                    lookahead.next();
+
+                    let name = match lookahead.next() {
+                        Some('B') => Some(self.month_name(date.month(), MonthForm::Nominative)),
+                        Some('b') => Some(self.month_name(date.month(), MonthForm::Short)),
+                        _ => None,
+                    };
+
+                    if name.is_some() {
+                        chars.next();
+                    }
+
+                    name
+                }
+                Some('B') => Some(self.month_name(date.month(), MonthForm::Genitive)),
+                Some('b' | 'h') => Some(self.month_name(date.month(), MonthForm::Short)),
+                Some('A') => Some(self.weekday_name(date.weekday(), WeekdayForm::Nominative)),
+                Some('a') => Some(self.weekday_name(date.weekday(), WeekdayForm::Short)),
+                _ => None,
+            };
+
+            match name {
+                Some(name) => {
+                    chars.next();
+                    output.push_str(name);
+                }
+                None => output.push('%'),
+            }
+        }
+
+        output
+    }
+
+    // Russian and Ukrainian share the one, few (2-4) and many rule, English only distinguishes one
+
+    pub fn plural<'a>(&self, number: i64, forms: &'a [String; 3]) -> &'a str {
+        let number = number.unsigned_abs();
+
+        match self {
+            Locale::English if number == 1 => &forms[0],
+            Locale::English => &forms[2],
+            Locale::Russian | Locale::Ukrainian => match (number % 10, number % 100) {
+                (1, n) if n != 11 => &forms[0],
+                (2..=4, n) if !(12..=14).contains(&n) => &forms[1],
+                _ => &forms[2],
+            },
+        }
+    }
+}
@@ -0,0 +1,126 @@
+mod context;
+mod error;
+mod locale;
+mod parser;
+mod render;
+mod value;
+mod variables;
+
+pub use context::TemplateContext;
+pub use error::TemplateError;
+pub use locale::Locale;
+pub use parser::Template;
+pub use value::{Value, ValueKind};
+
+// Texts that fail to parse are sent as they are, the posting rule validator reports them beforehand
+
+pub fn render(text: &str, context: &TemplateContext) -> String {
+    match Template::parse(text) {
+        Ok(template) => template.render(context),
+        Err(_) => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    // Wednesday, March 12, 2025, 12:00 in Moscow
+
+    fn context() -> TemplateContext {
+        let fire_time = Utc.with_ymd_and_hms(2025, 3, 12, 9, 0, 0).unwrap();
+
+        TemplateContext::new(fire_time, "Europe/Moscow").unwrap()
+    }
+
+    #[test]
+    fn renders_legacy_variables_as_before() {
+        let context = context();
+
+        assert_eq!(render("{date}", &context), "12.03.2025");
+        assert_eq!(render("{next_monday}", &context), "17.03.2025");
+        assert_eq!(render("{next_wednesday}", &context), "19.03.2025");
+        assert_eq!(render("{next_month}", &context), "апрель");
+    }
+
+    #[test]
+    fn renders_offsets() {
+        let context = context();
+
+        assert_eq!(render("{date+3d}", &context), "15.03.2025");
+        assert_eq!(render("{date-1w}", &context), "05.03.2025");
+        assert_eq!(render("{date+1m}", &context), "12.04.2025");
+        assert_eq!(render("{now+2h:%H:%M}", &context), "14:00");
+        assert_eq!(render("{day+1}", &context), "13");
+    }
+
+    #[test]
+    fn renders_localized_formats() {
+        let context = context();
+
+        assert_eq!(render("{date:%d %B}", &context), "12 марта");
+        assert_eq!(render("{date:%OB %Y}", &context), "март 2025");
+        assert_eq!(render("{month:prep}", &context), "марте");
+        assert_eq!(render("{weekday:acc}", &context), "среду");
+        assert_eq!(render("{weekday|upper}", &context), "СРЕДА");
+
+        let context = context.with_locale(Locale::English);
+
+        assert_eq!(render("{date:%A, %d %B}", &context), "Wednesday, 12 March");
+    }
+
+    #[test]
+    fn renders_conditionals() {
+        let context = context();
+        let text = "{if weekday in sat,sun}выходной{elif weekday == wed}среда{else}будни{end}";
+
+        assert_eq!(render(text, &context), "среда");
+        assert_eq!(render("{if day > 20}a{else}b{end}", &context), "b");
+        assert_eq!(render("{if month != mar}a{end}", &context), "");
+    }
+
+    #[test]
+    fn renders_plural_forms() {
+        let context = context();
+
+        assert_eq!(
+            render("{day} {day|plural:день,дня,дней}", &context),
+            "12 дней"
+        );
+        assert_eq!(
+            render("{day+9} {day+9|plural:день,дня,дней}", &context),
+            "21 день"
+        );
+        assert_eq!(
+            render("{day-10} {day-10|plural:день,дня,дней}", &context),
+            "2 дня"
+        );
+
+        let context = context.with_locale(Locale::English);
+
+        assert_eq!(render("{day-11|plural:day,days,days}", &context), "day");
+    }
+
+    #[test]
+    fn renders_custom_variables() {
+        let context =
+            context().with_variables(HashMap::from([("place".to_string(), "зал 2".to_string())]));
+
+        assert_eq!(render("{vars.place}", &context), "зал 2");
+        assert_eq!(render("{vars.missing}", &context), "{vars.missing}");
+    }
+
+    #[test]
+    fn keeps_invalid_templates_as_written() {
+        let context = context();
+
+        assert_eq!(render("{next_mondey}", &context), "{next_mondey}");
+        assert_eq!(render("{venue}", &context), "{venue}");
+        assert_eq!(
+            render(r#"{{"key": 1}} {{date}}"#, &context),
+            r#"{"key": 1} {date}"#
+        );
+    }
+}
@@ -0,0 +1,604 @@
+use crate::template::{
+    value::{OffsetUnit, ValueKind},
+    variables, TemplateError,
+};
+use std::collections::BTreeSet;
+
+// Template syntax:
+//   {name}, {name+3d}, {name-1w:%d %B}, {month:gen}, {name|upper}, {name|plural:день,дня,дней}
+//   {if weekday == mon}...{elif weekday in sat,sun}...{else}...{end}
+//   {vars.name} for custom variables of the bot or chat
+//   {{ and }} produce literal braces
+
+#[derive(Debug, Clone)]
+pub struct Template {
+    pub(crate) nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Text(String),
+    Output(Output),
+    If(IfBlock),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Output {
+    pub source: String,
+    pub expression: Expression,
+    pub format: Option<String>,
+    pub filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Expression {
+    pub variable: String,
+    pub offsets: Vec<(i64, Option<OffsetUnit>)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Filter {
+    Upper,
+    Lower,
+    Capitalize,
+    Plural([String; 3]),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct IfBlock {
+    pub branches: Vec<(Condition, Vec<Node>)>,
+    pub otherwise: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Condition {
+    pub expression: Expression,
+    pub operator: Operator,
+    pub values: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    In,
+}
+
+// Operators are matched in this order so that <= is not taken for <
+
+const OPERATORS: [(&str, Operator); 7] = [
+    ("==", Operator::Equal),
+    ("!=", Operator::NotEqual),
+    ("<=", Operator::LessOrEqual),
+    (">=", Operator::GreaterOrEqual),
+    ("<", Operator::Less),
+    (">", Operator::Greater),
+    (" in ", Operator::In),
+];
+
+// An open {if} block, nodes are collected into the current branch until the next {elif}, {else} or {end}
+
+struct Frame {
+    branches: Vec<(Option<Condition>, Vec<Node>)>,
+    condition: Option<Condition>,
+    nodes: Vec<Node>,
+    is_else: bool,
+}
+
+impl Frame {
+    fn new(condition: Option<Condition>) -> Self {
+        Self {
+            branches: vec![],
+            condition,
+            nodes: vec![],
+            is_else: false,
+        }
+    }
+
+    fn next_branch(&mut self, condition: Option<Condition>, is_else: bool) {
+        let nodes = std::mem::take(&mut self.nodes);
+        self.branches.push((self.condition.take(), nodes));
+        self.condition = condition;
+        self.is_else = is_else;
+    }
+
+    // Blocks with an invalid condition are dropped, the error has already been recorded
+
+    fn close(mut self) -> Option<IfBlock> {
+        let otherwise = if self.is_else {
+            std::mem::take(&mut self.nodes)
+        } else {
+            self.next_branch(None, false);
+            vec![]
+        };
+
+        let branches = self
+            .branches
+            .into_iter()
+            .map(|(condition, nodes)| condition.map(|condition| (condition, nodes)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(IfBlock {
+            branches,
+            otherwise,
+        })
+    }
+}
+
+impl Template {
+    // Collects every syntax error and unknown variable instead of stopping at the first one
+
+    pub fn parse(text: &str) -> Result<Self, Vec<TemplateError>> {
+        let mut errors = vec![];
+        let mut root = vec![];
+        let mut stack: Vec<Frame> = vec![];
+        let mut literal = String::new();
+        let mut rest = text;
+
+        while let Some(position) = rest.find(['{', '}']) {
+            literal.push_str(&rest[..position]);
+            let brace = &rest[position..];
+
+            if brace.starts_with("{{") || brace.starts_with("}}") {
+                literal.push_str(&brace[..1]);
+                rest = &brace[2..];
+                continue;
+            }
+
+            if let Some(after) = brace.strip_prefix('}') {
+                errors.push(TemplateError::new(
+                    "unexpected }, use }} for a literal brace",
+                ));
+                rest = after;
+                continue;
+            }
+
+            let end = match brace.find('}') {
+                Some(end) => end,
+                None => {
+                    errors.push(TemplateError::new("unclosed {, use {{ for a literal brace"));
+                    rest = "";
+                    break;
+                }
+            };
+
+            let source = &brace[..=end];
+            let tag = brace[1..end].trim();
+            rest = &brace[end + 1..];
+
+            if !literal.is_empty() {
+                push_node(
+                    &mut root,
+                    &mut stack,
+                    Node::Text(std::mem::take(&mut literal)),
+                );
+            }
+
+            if let Some(condition) = tag.strip_prefix("if ") {
+                stack.push(Frame::new(parse_condition(condition, &mut errors)));
+            } else if let Some(condition) = tag.strip_prefix("elif ") {
+                match stack.last_mut() {
+                    Some(frame) if !frame.is_else => {
+                        let condition = parse_condition(condition, &mut errors);
+                        frame.next_branch(condition, false);
+                    }
+                    Some(_) => errors.push(TemplateError::new("{elif} after {else}")),
+                    None => errors.push(TemplateError::new("{elif} without {if}")),
+                }
+            } else if tag == "else" {
+                match stack.last_mut() {
+                    Some(frame) if !frame.is_else => frame.next_branch(None, true),
+                    Some(_) => errors.push(TemplateError::new("{else} used twice")),
+                    None => errors.push(TemplateError::new("{else} without {if}")),
+                }
+            } else if tag == "end" {
+                match stack.pop() {
+                    Some(frame) => {
+                        if let Some(block) = frame.close() {
+                            push_node(&mut root, &mut stack, Node::If(block));
+                        }
+                    }
+                    None => errors.push(TemplateError::new("{end} without {if}")),
+                }
+            } else if let Some(output) = parse_output(source, tag, &mut errors) {
+                push_node(&mut root, &mut stack, Node::Output(output));
+            }
+        }
+
+        literal.push_str(rest);
+
+        if !literal.is_empty() {
+            push_node(&mut root, &mut stack, Node::Text(literal));
+        }
+
+        if !stack.is_empty() {
+            errors.push(TemplateError::new("{if} without {end}"));
+        }
+
+        if errors.is_empty() {
+            Ok(Self { nodes: root })
+        } else {
+            Err(errors)
+        }
+    }
+
+    // Names of the custom variables used in the outputs, without the vars. prefix
+
+    pub fn custom_variables(&self) -> BTreeSet<&str> {
+        let mut names = BTreeSet::new();
+        collect_custom_variables(&self.nodes, &mut names);
+        names
+    }
+}
+
+fn collect_custom_variables<'a>(nodes: &'a [Node], names: &mut BTreeSet<&'a str>) {
+    for node in nodes {
+        match node {
+            Node::Text(_) => {}
+            Node::Output(output) => names.extend(output.expression.custom_variable()),
+            Node::If(block) => {
+                for (_, nodes) in &block.branches {
+                    collect_custom_variables(nodes, names);
+                }
+
+                collect_custom_variables(&block.otherwise, names);
+            }
+        }
+    }
+}
+
+impl Expression {
+    fn custom_variable(&self) -> Option<&str> {
+        self.variable
+            .strip_prefix(variables::CUSTOM_VARIABLE_PREFIX)
+    }
+}
+
+fn push_node(root: &mut Vec<Node>, stack: &mut [Frame], node: Node) {
+    match stack.last_mut() {
+        Some(frame) => frame.nodes.push(node),
+        None => root.push(node),
+    }
+}
+
+fn parse_output(source: &str, tag: &str, errors: &mut Vec<TemplateError>) -> Option<Output> {
+    let mut parts = tag.split('|');
+    let head = parts.next().unwrap_or_default();
+
+    let (expression, format) = match head.split_once(':') {
+        Some((expression, format)) => (expression, Some(format.to_string())),
+        None => (head, None),
+    };
+
+    let (expression, kind) = parse_expression(expression, errors)?;
+
+    if let Some(format) = &format {
+        if !kind.supports_format() {
+            errors.push(TemplateError::new(format!(
+                "{} is a {} and cannot have a format",
+                expression.variable,
+                kind.name()
+            )));
+            return None;
+        }
+
+        if !kind.accepts_format(format) {
+            let expected = match kind {
+                ValueKind::Weekday => ", expected nom, acc or short",
+                ValueKind::Month => ", expected nom, gen, prep or short",
+                _ => "",
+            };
+
+            errors.push(TemplateError::new(format!(
+                "format {} of {} is invalid{}",
+                format, expression.variable, expected
+            )));
+            return None;
+        }
+    }
+
+    let mut filters = vec![];
+    let mut kind = kind;
+
+    for filter in parts {
+        let filter = filter.trim();
+
+        let (name, arguments) = match filter.split_once(':') {
+            Some((name, arguments)) => (name.trim(), Some(arguments)),
+            None => (filter, None),
+        };
+
+        let filter = match (name, arguments) {
+            ("upper", None) => Filter::Upper,
+            ("lower", None) => Filter::Lower,
+            ("capitalize", None) => Filter::Capitalize,
+            ("plural", Some(arguments)) => {
+                if kind != ValueKind::Number {
+                    errors.push(TemplateError::new(format!(
+                        "plural can only be applied to a number, {} is a {}",
+                        expression.variable,
+                        kind.name()
+                    )));
+                    return None;
+                }
+
+                let forms = arguments
+                    .split(',')
+                    .map(|form| form.trim().to_string())
+                    .collect::<Vec<_>>();
+
+                match <[String; 3]>::try_from(forms) {
+                    Ok(forms) => Filter::Plural(forms),
+                    Err(_) => {
+                        errors.push(TemplateError::new(
+                            "plural needs three forms, for example plural:день,дня,дней",
+                        ));
+                        return None;
+                    }
+                }
+            }
+            _ => {
+                errors.push(TemplateError::new(format!("unknown filter {}", filter)));
+                return None;
+            }
+        };
+
+        kind = ValueKind::Text;
+        filters.push(filter);
+    }
+
+    Some(Output {
+        source: source.to_string(),
+        expression,
+        format,
+        filters,
+    })
+}
+
+fn parse_expression(
+    text: &str,
+    errors: &mut Vec<TemplateError>,
+) -> Option<(Expression, ValueKind)> {
+    let text = text.trim();
+
+    let name_end = text.find(['+', '-']).unwrap_or(text.len());
+    let variable = text[..name_end].trim();
+
+    let is_valid_name = !variable.is_empty()
+        && variable
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.');
+
+    if !is_valid_name {
+        errors.push(TemplateError::new(format!(
+            "invalid expression {{{}}}",
+            text
+        )));
+        return None;
+    }
+
+    let kind = match variables::kind_of(variable) {
+        Some(kind) => kind,
+        None => {
+            let message = match variables::similar_name(variable) {
+                Some(suggestion) => format!(
+                    "unknown variable {{{}}}, did you mean {{{}}}",
+                    variable, suggestion
+                ),
+                None => format!("unknown variable {{{}}}", variable),
+            };
+
+            errors.push(TemplateError::new(message));
+            return None;
+        }
+    };
+
+    let mut offsets = vec![];
+    let mut rest = &text[name_end..];
+
+    while let Some(sign) = rest.chars().next() {
+        let offset_end = rest[1..].find(['+', '-']).map_or(rest.len(), |end| end + 1);
+        let offset = rest[1..offset_end].trim();
+        rest = &rest[offset_end..];
+
+        let digits_end = offset
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(offset.len());
+
+        let amount = offset[..digits_end].parse::<i64>().ok();
+        let unit = OffsetUnit::parse(offset[digits_end..].trim());
+
+        let (amount, unit) = match (amount, unit) {
+            (Some(amount), Some(unit)) => (amount, unit),
+            _ => {
+                errors.push(TemplateError::new(format!(
+                    "invalid offset {}{} of {}, expected a number with an optional unit h, d, w, m or y",
+                    sign, offset, variable
+                )));
+                return None;
+            }
+        };
+
+        if !kind.supports_offset(unit) {
+            errors.push(TemplateError::new(format!(
+                "offset {}{} cannot be applied to {}, it is a {}",
+                sign,
+                offset,
+                variable,
+                kind.name()
+            )));
+            return None;
+        }
+
+        offsets.push((if sign == '-' { -amount } else { amount }, unit));
+    }
+
+    Some((
+        Expression {
+            variable: variable.to_string(),
+            offsets,
+        },
+        kind,
+    ))
+}
+
+fn parse_condition(text: &str, errors: &mut Vec<TemplateError>) -> Option<Condition> {
+    let found = OPERATORS
+        .iter()
+        .find_map(|(symbol, operator)| text.split_once(symbol).map(|parts| (parts, *operator)));
+
+    let ((expression, literals), operator) = match found {
+        Some(found) => found,
+        None => {
+            errors.push(TemplateError::new(format!(
+                "condition {} must compare a variable using ==, !=, <, <=, >, >= or in",
+                text.trim()
+            )));
+            return None;
+        }
+    };
+
+    let (expression, kind) = parse_expression(expression, errors)?;
+
+    if !kind.is_ordered() {
+        errors.push(TemplateError::new(format!(
+            "{} is a {} and cannot be compared, only weekdays, months and numbers can",
+            expression.variable,
+            kind.name()
+        )));
+        return None;
+    }
+
+    let literals = if operator == Operator::In {
+        literals.split(',').collect::<Vec<_>>()
+    } else {
+        vec![literals]
+    };
+
+    let mut values = vec![];
+
+    for literal in literals {
+        match kind.parse_literal(literal) {
+            Some(value) => values.push(value),
+            None => {
+                errors.push(TemplateError::new(format!(
+                    "{} is not a valid {} to compare {} with",
+                    literal.trim(),
+                    kind.name(),
+                    expression.variable
+                )));
+                return None;
+            }
+        }
+    }
+
+    Some(Condition {
+        expression,
+        operator,
+        values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(text: &str) -> Vec<String> {
+        match Template::parse(text) {
+            Ok(_) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.message).collect(),
+        }
+    }
+
+    #[test]
+    fn parses_valid_templates() {
+        assert!(errors("{date+3d:%d %B} {weekday:acc} {day|plural:день,дня,дней}").is_empty());
+        assert!(errors("{if weekday in sat,sun}a{elif day > 20}b{else}c{end}").is_empty());
+        assert!(errors("{vars.place}").is_empty());
+    }
+
+    #[test]
+    fn reports_unbalanced_blocks() {
+        assert_eq!(errors("{if weekday == mon}a"), vec!["{if} without {end}"]);
+        assert_eq!(errors("a{end}"), vec!["{end} without {if}"]);
+        assert_eq!(errors("{else}"), vec!["{else} without {if}"]);
+        assert_eq!(errors("{elif day > 1}"), vec!["{elif} without {if}"]);
+        assert_eq!(
+            errors("{if day > 1}a{else}b{else}c{end}"),
+            vec!["{else} used twice"]
+        );
+        assert_eq!(
+            errors("{if day > 1}a{else}b{elif day < 1}c{end}"),
+            vec!["{elif} after {else}"]
+        );
+    }
+
+    #[test]
+    fn suggests_similar_variable_names() {
+        assert_eq!(
+            errors("{next_mondey}"),
+            vec!["unknown variable {next_mondey}, did you mean {next_monday}"]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_offsets_formats_and_filters() {
+        assert_eq!(
+            errors("{date+3x}"),
+            vec!["invalid offset +3x of date, expected a number with an optional unit h, d, w, m or y"]
+        );
+        assert_eq!(
+            errors("{day|plural:день,дня}"),
+            vec!["plural needs three forms, for example plural:день,дня,дней"]
+        );
+        assert_eq!(
+            errors("{month|plural:a,b,c}"),
+            vec!["plural can only be applied to a number, month is a month"]
+        );
+        assert_eq!(errors("{day|reverse}"), vec!["unknown filter reverse"]);
+        assert_eq!(errors("{month:dat}").len(), 1);
+        assert_eq!(errors("{weekday+1h}").len(), 1);
+    }
+
+    #[test]
+    fn collects_every_error() {
+        assert_eq!(errors("{next_mondey} {day|reverse} {end}").len(), 3);
+    }
+
+    #[test]
+    fn rejects_unknown_variables() {
+        assert_eq!(errors("{venue}"), vec!["unknown variable {venue}"]);
+        assert_eq!(errors("{next_mon}"), vec!["unknown variable {next_mon}"]);
+        assert_eq!(errors("{date is}"), vec!["invalid expression {date is}"]);
+    }
+
+    #[test]
+    fn requires_doubled_braces_for_literals() {
+        assert!(errors("{{\"key\": 1}}").is_empty());
+        assert_eq!(
+            errors("a } b"),
+            vec!["unexpected }, use }} for a literal brace"]
+        );
+        assert_eq!(
+            errors("a { b"),
+            vec!["unclosed {, use {{ for a literal brace"]
+        );
+    }
+
+    #[test]
+    fn collects_custom_variables() {
+        let template = Template::parse(
+            "{vars.place} {if day > 1}{vars.count}{vars.note|upper}{else}{date}{end}",
+        )
+        .unwrap();
+
+        assert_eq!(
+            template.custom_variables(),
+            BTreeSet::from(["count", "note", "place"])
+        );
+    }
+}
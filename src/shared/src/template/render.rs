@@ -0,0 +1,111 @@
+use crate::template::{
+    parser::{Condition, Expression, Filter, Node, Operator, Output},
+    variables, Template, TemplateContext, Value,
+};
+
+impl Template {
+    pub fn render(&self, context: &TemplateContext) -> String {
+        let mut output = String::new();
+        render_nodes(&self.nodes, context, &mut output);
+        output
+    }
+}
+
+fn render_nodes(nodes: &[Node], context: &TemplateContext, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Output(value) => output.push_str(&render_output(value, context)),
+            Node::If(block) => {
+                let nodes = block
+                    .branches
+                    .iter()
+                    .find(|(condition, _)| is_satisfied(condition, context))
+                    .map(|(_, nodes)| nodes)
+                    .unwrap_or(&block.otherwise);
+
+                render_nodes(nodes, context, output);
+            }
+        }
+    }
+}
+
+// A value that cannot be computed, for example a date out of range, is left as written
+
+fn render_output(output: &Output, context: &TemplateContext) -> String {
+    let mut value = match evaluate(&output.expression, context) {
+        Some(value) => value,
+        None => return output.source.clone(),
+    };
+
+    for filter in &output.filters {
+        let text = match (filter, &value) {
+            (Filter::Plural(forms), Value::Number(number)) => {
+                context.locale.plural(*number, forms).to_string()
+            }
+            (Filter::Plural(_), _) => return output.source.clone(),
+            (_, value) => match render_value(value, output, context) {
+                Some(text) => apply_case(filter, &text),
+                None => return output.source.clone(),
+            },
+        };
+
+        value = Value::Text(text);
+    }
+
+    render_value(&value, output, context).unwrap_or_else(|| output.source.clone())
+}
+
+// The format applies to the variable itself, filters turn it into text that is written as is
+
+fn render_value(value: &Value, output: &Output, context: &TemplateContext) -> Option<String> {
+    match value {
+        Value::Text(_) => value.render(None, context.locale),
+        _ => value.render(output.format.as_deref(), context.locale),
+    }
+}
+
+fn evaluate(expression: &Expression, context: &TemplateContext) -> Option<Value> {
+    let mut value = variables::resolve(&expression.variable, context)?;
+
+    for (amount, unit) in &expression.offsets {
+        value = value.offset(*amount, *unit)?;
+    }
+
+    Some(value)
+}
+
+fn is_satisfied(condition: &Condition, context: &TemplateContext) -> bool {
+    let value = match evaluate(&condition.expression, context).and_then(|value| value.ordinal()) {
+        Some(value) => value,
+        None => return false,
+    };
+
+    let expected = condition.values.first().copied().unwrap_or_default();
+
+    match condition.operator {
+        Operator::Equal => value == expected,
+        Operator::NotEqual => value != expected,
+        Operator::Less => value < expected,
+        Operator::LessOrEqual => value <= expected,
+        Operator::Greater => value > expected,
+        Operator::GreaterOrEqual => value >= expected,
+        Operator::In => condition.values.contains(&value),
+    }
+}
+
+fn apply_case(filter: &Filter, text: &str) -> String {
+    match filter {
+        Filter::Upper => text.to_uppercase(),
+        Filter::Lower => text.to_lowercase(),
+        Filter::Capitalize => {
+            let mut chars = text.chars();
+
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        Filter::Plural(_) => text.to_string(),
+    }
+}
@@ -0,0 +1,250 @@
+use crate::template::{
+    locale::{MonthForm, WeekdayForm},
+    Locale,
+};
+use chrono::{DateTime, Duration, Months, NaiveDate, Weekday};
+use chrono_tz::Tz;
+use std::fmt::Write;
+
+const WEEKDAY_LITERALS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+const MONTH_LITERALS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Date(NaiveDate),
+    DateTime(DateTime<Tz>),
+    Weekday(Weekday),
+    Month(u32),
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Date,
+    DateTime,
+    Weekday,
+    Month,
+    Number,
+    Text,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetUnit {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl ValueKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ValueKind::Date => "date",
+            ValueKind::DateTime => "date and time",
+            ValueKind::Weekday => "weekday",
+            ValueKind::Month => "month",
+            ValueKind::Number => "number",
+            ValueKind::Text => "text",
+        }
+    }
+
+    // Units are optional where the kind has a natural one: days for weekdays, months for months
+
+    pub fn supports_offset(&self, unit: Option<OffsetUnit>) -> bool {
+        match (self, unit) {
+            (ValueKind::Date, Some(unit)) => unit != OffsetUnit::Hours,
+            (ValueKind::DateTime, Some(_)) => true,
+            (ValueKind::Weekday, None | Some(OffsetUnit::Days)) => true,
+            (ValueKind::Month, None | Some(OffsetUnit::Months)) => true,
+            (ValueKind::Number, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn supports_format(&self) -> bool {
+        matches!(
+            self,
+            ValueKind::Date | ValueKind::DateTime | ValueKind::Weekday | ValueKind::Month
+        )
+    }
+
+    // Formats are checked by writing a sample value, chrono rejects time specifiers such as %H for dates
+
+    pub fn accepts_format(&self, format: &str) -> bool {
+        let sample = match self {
+            ValueKind::Date => Value::Date(NaiveDate::default()),
+            ValueKind::DateTime => Value::DateTime(DateTime::UNIX_EPOCH.with_timezone(&Tz::UTC)),
+            ValueKind::Weekday => Value::Weekday(Weekday::Mon),
+            ValueKind::Month => Value::Month(1),
+            _ => return false,
+        };
+
+        sample.render(Some(format), Locale::default()).is_some()
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        matches!(
+            self,
+            ValueKind::Weekday | ValueKind::Month | ValueKind::Number
+        )
+    }
+
+    // Weekdays and months are compared by their number (Monday and January are 1) or short English name
+
+    pub fn parse_literal(&self, literal: &str) -> Option<i64> {
+        let literal = literal.trim();
+
+        let names: &[&str] = match self {
+            ValueKind::Weekday => &WEEKDAY_LITERALS,
+            ValueKind::Month => &MONTH_LITERALS,
+            _ => &[],
+        };
+
+        if let Some(position) = names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(literal))
+        {
+            return Some(position as i64 + 1);
+        }
+
+        let value = literal.parse::<i64>().ok()?;
+
+        match self {
+            ValueKind::Weekday => (1..=7).contains(&value).then_some(value),
+            ValueKind::Month => (1..=12).contains(&value).then_some(value),
+            _ => Some(value),
+        }
+    }
+}
+
+impl OffsetUnit {
+    pub fn parse(unit: &str) -> Option<Option<Self>> {
+        match unit {
+            "" => Some(None),
+            "h" => Some(Some(OffsetUnit::Hours)),
+            "d" => Some(Some(OffsetUnit::Days)),
+            "w" => Some(Some(OffsetUnit::Weeks)),
+            "m" => Some(Some(OffsetUnit::Months)),
+            "y" => Some(Some(OffsetUnit::Years)),
+            _ => None,
+        }
+    }
+}
+
+impl Value {
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Value::Date(_) => ValueKind::Date,
+            Value::DateTime(_) => ValueKind::DateTime,
+            Value::Weekday(_) => ValueKind::Weekday,
+            Value::Month(_) => ValueKind::Month,
+            Value::Number(_) => ValueKind::Number,
+            Value::Text(_) => ValueKind::Text,
+        }
+    }
+
+    pub fn ordinal(&self) -> Option<i64> {
+        match self {
+            Value::Weekday(weekday) => Some(weekday.number_from_monday() as i64),
+            Value::Month(month) => Some(*month as i64),
+            Value::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub fn offset(self, amount: i64, unit: Option<OffsetUnit>) -> Option<Value> {
+        match (self, unit) {
+            (Value::Date(date), Some(unit)) => Some(Value::Date(match unit {
+                OffsetUnit::Hours => return None,
+                OffsetUnit::Days => date.checked_add_signed(Duration::days(amount))?,
+                OffsetUnit::Weeks => date.checked_add_signed(Duration::weeks(amount))?,
+                OffsetUnit::Months => add_months(date, amount)?,
+                OffsetUnit::Years => add_months(date, amount.checked_mul(12)?)?,
+            })),
+            (Value::DateTime(date_time), Some(unit)) => Some(Value::DateTime(match unit {
+                OffsetUnit::Hours => date_time.checked_add_signed(Duration::hours(amount))?,
+                OffsetUnit::Days => date_time.checked_add_signed(Duration::days(amount))?,
+                OffsetUnit::Weeks => date_time.checked_add_signed(Duration::weeks(amount))?,
+                OffsetUnit::Months => add_date_time_months(date_time, amount)?,
+                OffsetUnit::Years => add_date_time_months(date_time, amount.checked_mul(12)?)?,
+            })),
+            (Value::Weekday(weekday), None | Some(OffsetUnit::Days)) => {
+                let days = weekday.num_days_from_monday() as i64 + amount;
+                Some(Value::Weekday(
+                    Weekday::try_from(days.rem_euclid(7) as u8).ok()?,
+                ))
+            }
+            (Value::Month(month), None | Some(OffsetUnit::Months)) => {
+                let months = month as i64 - 1 + amount;
+                Some(Value::Month(months.rem_euclid(12) as u32 + 1))
+            }
+            (Value::Number(number), None) => Some(Value::Number(number.checked_add(amount)?)),
+            _ => None,
+        }
+    }
+
+    // Returns None when the format cannot be applied to the value, chrono reports it only while writing.
+    // Weekdays and months take the grammatical case as their format
+
+    pub fn render(&self, format: Option<&str>, locale: Locale) -> Option<String> {
+        let mut output = String::new();
+
+        match (self, format) {
+            (Value::Date(date), format) => {
+                let format = locale.localize_format(format.unwrap_or("%d.%m.%Y"), *date);
+                write!(output, "{}", date.format(&format)).ok()?
+            }
+            (Value::DateTime(date_time), format) => {
+                let format = locale
+                    .localize_format(format.unwrap_or("%d.%m.%Y %H:%M"), date_time.date_naive());
+                write!(output, "{}", date_time.format(&format)).ok()?
+            }
+            (Value::Weekday(weekday), format) => {
+                let form = match format {
+                    Some(format) => WeekdayForm::parse(format)?,
+                    None => WeekdayForm::Nominative,
+                };
+
+                output.push_str(locale.weekday_name(*weekday, form))
+            }
+            (Value::Month(month), format) => {
+                let form = match format {
+                    Some(format) => MonthForm::parse(format)?,
+                    None => MonthForm::Nominative,
+                };
+
+                output.push_str(locale.month_name(*month, form))
+            }
+            (_, Some(_)) => return None,
+            (Value::Number(number), None) => output.push_str(&number.to_string()),
+            (Value::Text(text), None) => output.push_str(text),
+        }
+
+        Some(output)
+    }
+}
+
+fn add_months(date: NaiveDate, amount: i64) -> Option<NaiveDate> {
+    let months = Months::new(u32::try_from(amount.unsigned_abs()).ok()?);
+
+    if amount >= 0 {
+        date.checked_add_months(months)
+    } else {
+        date.checked_sub_months(months)
+    }
+}
+
+fn add_date_time_months(date_time: DateTime<Tz>, amount: i64) -> Option<DateTime<Tz>> {
+    let months = Months::new(u32::try_from(amount.unsigned_abs()).ok()?);
+
+    if amount >= 0 {
+        date_time.checked_add_months(months)
+    } else {
+        date_time.checked_sub_months(months)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    data::TemplateVariable,
+    template::{TemplateContext, Value, ValueKind},
+};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+const VARIABLES: [(&str, ValueKind); 15] = [
+    ("now", ValueKind::DateTime),
+    ("date", ValueKind::Date),
+    ("weekday", ValueKind::Weekday),
+    ("day", ValueKind::Number),
+    ("week", ValueKind::Number),
+    ("month", ValueKind::Month),
+    ("year", ValueKind::Number),
+    ("next_monday", ValueKind::Date),
+    ("next_tuesday", ValueKind::Date),
+    ("next_wednesday", ValueKind::Date),
+    ("next_thursday", ValueKind::Date),
+    ("next_friday", ValueKind::Date),
+    ("next_saturday", ValueKind::Date),
+    ("next_sunday", ValueKind::Date),
+    ("next_month", ValueKind::Month),
+];
+
+// Custom variables are defined by admins per bot or chat and are stored apart from the rule, so the parser
+// accepts any valid name under the prefix and the validator checks it against the defined ones
+
+pub const CUSTOM_VARIABLE_PREFIX: &str = "vars.";
+
+pub fn kind_of(name: &str) -> Option<ValueKind> {
+    if let Some(name) = name.strip_prefix(CUSTOM_VARIABLE_PREFIX) {
+        return TemplateVariable::is_valid_name(name).then_some(ValueKind::Text);
+    }
+
+    VARIABLES
+        .iter()
+        .find(|(variable, _)| *variable == name)
+        .map(|(_, kind)| *kind)
+}
+
+pub fn similar_name(name: &str) -> Option<&'static str> {
+    VARIABLES
+        .iter()
+        .map(|(variable, _)| *variable)
+        .find(|variable| is_one_edit_away(name, variable))
+}
+
+fn is_one_edit_away(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+
+    let prefix = shorter
+        .iter()
+        .zip(longer)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    if shorter.len() == longer.len() {
+        prefix < shorter.len() && shorter[prefix + 1..] == longer[prefix + 1..]
+    } else {
+        shorter[prefix..] == longer[prefix + 1..]
+    }
+}
+
+pub fn resolve(name: &str, context: &TemplateContext) -> Option<Value> {
+    if let Some(name) = name.strip_prefix(CUSTOM_VARIABLE_PREFIX) {
+        return context.variables.get(name).cloned().map(Value::Text);
+    }
+
+    let today = context.fire_time.date_naive();
+
+    let value = match name {
+        "now" => Value::DateTime(context.fire_time),
+        "date" => Value::Date(today),
+        "weekday" => Value::Weekday(today.weekday()),
+        "day" => Value::Number(today.day() as i64),
+        "week" => Value::Number(today.iso_week().week() as i64),
+        "month" => Value::Month(today.month()),
+        "year" => Value::Number(today.year() as i64),
+        "next_monday" => Value::Date(next_weekday(today, Weekday::Mon)),
+        "next_tuesday" => Value::Date(next_weekday(today, Weekday::Tue)),
+        "next_wednesday" => Value::Date(next_weekday(today, Weekday::Wed)),
+        "next_thursday" => Value::Date(next_weekday(today, Weekday::Thu)),
+        "next_friday" => Value::Date(next_weekday(today, Weekday::Fri)),
+        "next_saturday" => Value::Date(next_weekday(today, Weekday::Sat)),
+        "next_sunday" => Value::Date(next_weekday(today, Weekday::Sun)),
+        "next_month" => Value::Month(today.month() % 12 + 1),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+// The next weekday is always in the future, a post on Monday gets the Monday a week later
+
+fn next_weekday(today: NaiveDate, target: Weekday) -> NaiveDate {
+    let days_until =
+        (target.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64 + 7)
+            % 7;
+
+    let days_until = if days_until == 0 { 7 } else { days_until };
+
+    today + Duration::days(days_until)
+}
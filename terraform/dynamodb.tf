@@ -1,3 +1,20 @@
+resource "aws_dynamodb_table" "blackout_calendars" {
+  name         = "${local.app_name}-blackout-calendars"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "BotId"
+  range_key    = "Id"
+
+  attribute {
+    name = "BotId"
+    type = "S"
+  }
+
+  attribute {
+    name = "Id"
+    type = "S"
+  }
+}
+
 resource "aws_dynamodb_table" "bots" {
   name             = "${local.app_name}-bots"
   billing_mode     = "PAY_PER_REQUEST"
@@ -47,6 +64,28 @@ resource "aws_dynamodb_table" "poll_action_log" {
   }
 }
 
+resource "aws_dynamodb_table" "poll_action_log_records" {
+  name         = "${local.app_name}-poll-action-log-records"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "PollId"
+  range_key    = "UpdateId"
+
+  attribute {
+    name = "PollId"
+    type = "S"
+  }
+
+  attribute {
+    name = "UpdateId"
+    type = "N"
+  }
+
+  ttl {
+    attribute_name = "ExpiresAt"
+    enabled        = true
+  }
+}
+
 resource "aws_dynamodb_table" "posting_rules" {
   name             = "${local.app_name}-posting-rules"
   billing_mode     = "PAY_PER_REQUEST"
@@ -100,3 +139,37 @@ resource "aws_dynamodb_table" "posts" {
     projection_type = "ALL"
   }
 }
+
+resource "aws_dynamodb_table" "template_variables" {
+  name         = "${local.app_name}-template-variables"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "BotId"
+  range_key    = "Id"
+
+  attribute {
+    name = "BotId"
+    type = "S"
+  }
+
+  attribute {
+    name = "Id"
+    type = "S"
+  }
+}
+
+resource "aws_dynamodb_table" "queue_items" {
+  name         = "${local.app_name}-queue-items"
+  billing_mode = "PAY_PER_REQUEST"
+  hash_key     = "PostingRuleId"
+  range_key    = "Position"
+
+  attribute {
+    name = "PostingRuleId"
+    type = "S"
+  }
+
+  attribute {
+    name = "Position"
+    type = "N"
+  }
+}
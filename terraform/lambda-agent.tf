@@ -18,10 +18,13 @@ resource "aws_lambda_function" "agent_lambda" {
 
   environment {
     variables = {
-      BOTS_TABLE            = aws_dynamodb_table.bots.name
-      POLL_ACTION_LOG_TABLE = aws_dynamodb_table.poll_action_log.name
-      POSTING_RULES_TABLE   = aws_dynamodb_table.posting_rules.name
-      POSTS_TABLE           = aws_dynamodb_table.posts.name
+      BOTS_TABLE                    = aws_dynamodb_table.bots.name
+      POLL_ACTION_LOG_TABLE         = aws_dynamodb_table.poll_action_log.name
+      POLL_ACTION_LOG_RECORDS_TABLE = aws_dynamodb_table.poll_action_log_records.name
+      POSTING_RULES_TABLE           = aws_dynamodb_table.posting_rules.name
+      POSTS_TABLE                   = aws_dynamodb_table.posts.name
+      TEMPLATE_VARIABLES_TABLE      = aws_dynamodb_table.template_variables.name
+      QUEUE_ITEMS_TABLE             = aws_dynamodb_table.queue_items.name
     }
   }
 
@@ -68,8 +71,11 @@ resource "aws_iam_policy" "agent_lambda_policy" {
         Resource = [
           aws_dynamodb_table.bots.arn,
           aws_dynamodb_table.poll_action_log.arn,
+          aws_dynamodb_table.poll_action_log_records.arn,
           aws_dynamodb_table.posting_rules.arn,
-          aws_dynamodb_table.posts.arn
+          aws_dynamodb_table.posts.arn,
+          aws_dynamodb_table.template_variables.arn,
+          aws_dynamodb_table.queue_items.arn
         ]
       },
     ]
@@ -0,0 +1,118 @@
+data "archive_file" "post_cleanup_lambda_zip" {
+  type        = "zip"
+  source_file = "../post-cleanup-lambda/bootstrap"
+  output_path = "post_cleanup_lambda.zip"
+}
+
+resource "aws_lambda_function" "post_cleanup_lambda" {
+  filename      = data.archive_file.post_cleanup_lambda_zip.output_path
+  function_name = "${local.app_name}-post-cleanup"
+  role          = aws_iam_role.post_cleanup_lambda_role.arn
+  handler       = "bootstrap"
+  runtime       = "provided.al2023"
+  timeout       = 30
+  memory_size   = 128
+  architectures = ["arm64"]
+
+  source_code_hash = data.archive_file.post_cleanup_lambda_zip.output_base64sha256
+
+  environment {
+    variables = {
+      BOTS_TABLE                    = aws_dynamodb_table.bots.name
+      POLL_ACTION_LOG_TABLE         = aws_dynamodb_table.poll_action_log.name
+      POLL_ACTION_LOG_RECORDS_TABLE = aws_dynamodb_table.poll_action_log_records.name
+    }
+  }
+
+  depends_on = [
+    aws_iam_role_policy_attachment.post_cleanup_lambda_basic_execution,
+    aws_cloudwatch_log_group.post_cleanup_lambda_logs
+  ]
+}
+
+resource "aws_iam_role" "post_cleanup_lambda_role" {
+  name = "${local.app_name}-post-cleanup-lambda-role"
+
+  assume_role_policy = jsonencode({
+    Version = "2012-10-17"
+    Statement = [
+      {
+        Action = "sts:AssumeRole"
+        Effect = "Allow"
+        Principal = {
+          Service = "lambda.amazonaws.com"
+        }
+      }
+    ]
+  })
+}
+
+resource "aws_iam_policy" "post_cleanup_lambda_policy" {
+  name = "${local.app_name}-post-cleanup-lambda-policy"
+
+  policy = jsonencode({
+    Version = "2012-10-17"
+    Statement = [
+      {
+        Effect = "Allow"
+        Action = [
+          "dynamodb:GetRecords",
+          "dynamodb:GetShardIterator",
+          "dynamodb:DescribeStream",
+          "dynamodb:ListStreams"
+        ]
+        Resource = [
+          "${aws_dynamodb_table.posts.arn}/stream/*"
+        ]
+      },
+      {
+        Effect = "Allow"
+        Action = [
+          "dynamodb:GetItem",
+          "dynamodb:Query"
+        ]
+        Resource = [
+          aws_dynamodb_table.bots.arn,
+          aws_dynamodb_table.poll_action_log_records.arn,
+          "${aws_dynamodb_table.poll_action_log.arn}/index/ChatMessageIndex"
+        ]
+      }
+    ]
+  })
+}
+
+resource "aws_iam_role_policy_attachment" "post_cleanup_lambda_policy_attachment" {
+  role       = aws_iam_role.post_cleanup_lambda_role.name
+  policy_arn = aws_iam_policy.post_cleanup_lambda_policy.arn
+}
+
+resource "aws_iam_role_policy_attachment" "post_cleanup_lambda_basic_execution" {
+  role       = aws_iam_role.post_cleanup_lambda_role.name
+  policy_arn = "arn:aws:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole"
+}
+
+resource "aws_cloudwatch_log_group" "post_cleanup_lambda_logs" {
+  name              = "/aws/lambda/${local.app_name}-post-cleanup"
+  retention_in_days = 14
+}
+
+resource "aws_lambda_event_source_mapping" "post_cleanup_dynamodb_stream" {
+  event_source_arn                   = aws_dynamodb_table.posts.stream_arn
+  function_name                      = aws_lambda_function.post_cleanup_lambda.arn
+  starting_position                  = "LATEST"
+  batch_size                         = 1
+  maximum_batching_window_in_seconds = 5
+  maximum_retry_attempts             = 1
+
+  filter_criteria {
+    filter {
+      pattern = jsonencode({
+        eventName = ["REMOVE"]
+        userIdentity = {
+          type        = ["Service"]
+          principalId = ["dynamodb.amazonaws.com"]
+        }
+      })
+    }
+  }
+}
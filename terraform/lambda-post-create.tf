@@ -18,10 +18,17 @@ resource "aws_lambda_function" "post_create_lambda" {
 
   environment {
     variables = {
-      BOTS_TABLE            = aws_dynamodb_table.bots.name
-      POLL_ACTION_LOG_TABLE = aws_dynamodb_table.poll_action_log.name
-      POSTING_RULES_TABLE   = aws_dynamodb_table.posting_rules.name
-      POSTS_TABLE           = aws_dynamodb_table.posts.name
+      BLACKOUT_CALENDARS_TABLE      = aws_dynamodb_table.blackout_calendars.name
+      BOTS_TABLE                    = aws_dynamodb_table.bots.name
+      POLL_ACTION_LOG_TABLE         = aws_dynamodb_table.poll_action_log.name
+      POLL_ACTION_LOG_RECORDS_TABLE = aws_dynamodb_table.poll_action_log_records.name
+      POSTING_RULES_TABLE           = aws_dynamodb_table.posting_rules.name
+      POSTS_TABLE                   = aws_dynamodb_table.posts.name
+      SCHEDULER_ROLE_ARN            = aws_iam_role.scheduler_role.arn
+      SCHEDULER_GROUP_NAME          = aws_scheduler_schedule_group.scheduler_group.name
+      SCHEDULE_PREFIX               = "${local.app_name}-posting-"
+      TEMPLATE_VARIABLES_TABLE      = aws_dynamodb_table.template_variables.name
+      QUEUE_ITEMS_TABLE             = aws_dynamodb_table.queue_items.name
     }
   }
 
@@ -65,12 +72,16 @@ resource "aws_iam_policy" "post_create_lambda_policy" {
           "dynamodb:Query"
         ]
         Resource = [
+          aws_dynamodb_table.blackout_calendars.arn,
           aws_dynamodb_table.bots.arn,
           aws_dynamodb_table.poll_action_log.arn,
+          aws_dynamodb_table.poll_action_log_records.arn,
           "${aws_dynamodb_table.poll_action_log.arn}/index/ChatMessageIndex",
           aws_dynamodb_table.posting_rules.arn,
           aws_dynamodb_table.posts.arn,
-          "${aws_dynamodb_table.posts.arn}/index/PostingRuleIndex"
+          "${aws_dynamodb_table.posts.arn}/index/PostingRuleIndex",
+          aws_dynamodb_table.template_variables.arn,
+          aws_dynamodb_table.queue_items.arn
         ]
       },
       {
@@ -82,6 +93,26 @@ resource "aws_iam_policy" "post_create_lambda_policy" {
           aws_lambda_function.post_create_lambda.arn
         ]
       },
+      {
+        Effect = "Allow"
+        Action = [
+          "scheduler:GetSchedule",
+          "scheduler:CreateSchedule",
+          "scheduler:UpdateSchedule"
+        ]
+        Resource = [
+          "arn:aws:scheduler:${data.aws_region.current.id}:${data.aws_caller_identity.current.account_id}:schedule/${aws_scheduler_schedule_group.scheduler_group.name}/*"
+        ]
+      },
+      {
+        Effect = "Allow"
+        Action = [
+          "iam:PassRole"
+        ]
+        Resource = [
+          aws_iam_role.scheduler_role.arn
+        ]
+      },
     ]
   })
 }
@@ -78,6 +78,24 @@ resource "aws_iam_policy" "schedule_sync_lambda_policy" {
           "arn:aws:scheduler:${data.aws_region.current.id}:${data.aws_caller_identity.current.account_id}:schedule/${aws_scheduler_schedule_group.scheduler_group.name}/*"
         ]
       },
+      {
+        Effect = "Allow"
+        Action = [
+          "scheduler:ListSchedules"
+        ]
+        Resource = [
+          "*"
+        ]
+      },
+      {
+        Effect = "Allow"
+        Action = [
+          "lambda:InvokeFunction"
+        ]
+        Resource = [
+          aws_lambda_function.post_create_lambda.arn
+        ]
+      },
       {
         Effect = "Allow"
         Action = [